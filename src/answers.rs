@@ -0,0 +1,252 @@
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+pub const DEFAULT_ANSWERS: &str = "answers.toml";
+
+/// Confirmed answers, keyed by day and input, named by
+/// [`Source::key`](crate::input::Source::key). Stored as a small TOML
+/// file with one table per input:
+///
+/// ```toml
+/// [day1."input/day1.txt"]
+/// part1 = "1147"
+/// part2 = "6789"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u8, String), [Option<String>; 2]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, input: &str, part: u8) -> Option<&str> {
+        let answers = self.entries.get(&(day, input.to_string()))?;
+        answers.get(part_index(part)?)?.as_deref()
+    }
+
+    pub fn set(&mut self, day: u8, input: &str, part: u8, answer: &str) {
+        let Some(index) = part_index(part) else {
+            return;
+        };
+        let answers = self.entries.entry((day, input.to_string())).or_default();
+        answers[index] = Some(answer.to_string());
+    }
+
+    pub fn check(&self, day: u8, input: &str, part: u8, answer: &str) -> Verdict {
+        match self.get(day, input, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Loads answers from `path`, treating a missing file as empty.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| {
+                let message = format!("{}:{err}", path.display());
+                io::Error::new(io::ErrorKind::InvalidData, message)
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_toml())
+    }
+
+    /// Parses the TOML subset written by [`Answers::to_toml`]. Errors carry
+    /// the 1-based line number, as `"<line>: <message>"`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+        let mut table: Option<(u8, String)> = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or(format!("{line_number}: expected ']' to close the table"))?;
+                table = Some(
+                    parse_header(header)
+                        .ok_or(format!(r#"{line_number}: expected [dayN."<input>"]"#))?,
+                );
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("{line_number}: expected key = value"))?;
+            let (day, input) = table.as_ref().ok_or(format!(
+                "{line_number}: answer outside of a [dayN.\"<input>\"] table"
+            ))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                key => return Err(format!("{line_number}: unknown key {key:?}")),
+            };
+            let value = parse_value(value.trim()).ok_or(format!(
+                "{line_number}: expected a quoted string or integer"
+            ))?;
+            answers.set(*day, input, part, &value);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut out = String::from("# Confirmed answers per day and input file.\n");
+        for ((day, input), parts) in &self.entries {
+            out += &format!("\n[day{day}.{}]\n", quote(input));
+            for (index, answer) in parts.iter().enumerate() {
+                if let Some(answer) = answer {
+                    out += &format!("part{} = {}\n", index + 1, quote(answer));
+                }
+            }
+        }
+        out
+    }
+}
+
+fn part_index(part: u8) -> Option<usize> {
+    match part {
+        1 | 2 => Some(part as usize - 1),
+        _ => None,
+    }
+}
+
+fn parse_header(header: &str) -> Option<(u8, String)> {
+    let (day, input) = header.trim().split_once('.')?;
+    let day = day.strip_prefix("day")?.parse().ok()?;
+    let input = unquote(input.trim())?;
+    Some((day, input))
+}
+
+/// Reads a quoted string or an integer, ignoring a trailing comment.
+pub(crate) fn parse_value(value: &str) -> Option<String> {
+    if value.starts_with('"') {
+        return unquote(value);
+    }
+    let value = value.split('#').next().unwrap_or("").trim();
+    value.parse::<i128>().ok().map(|num| num.to_string())
+}
+
+pub(crate) fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Reads one basic TOML string, allowing a trailing comment.
+pub(crate) fn unquote(text: &str) -> Option<String> {
+    let mut chars = text.strip_prefix('"')?.chars();
+    let mut out = String::new();
+    loop {
+        match chars.next()? {
+            '"' => break,
+            '\\' => match chars.next()? {
+                '"' => out.push('"'),
+                '\\' => out.push('\\'),
+                'n' => out.push('\n'),
+                't' => out.push('\t'),
+                _ => return None,
+            },
+            char => out.push(char),
+        }
+    }
+    let rest = chars.as_str().trim();
+    (rest.is_empty() || rest.starts_with('#')).then_some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = r#"
+# shared inputs
+[day1."input/day1.txt"]
+part1 = "1147"
+part2 = 6789 # from the site
+
+[day9."inputs/alice/day9.txt"]
+part1 = "50"
+"#;
+        let got = Answers::parse(text).unwrap();
+
+        assert_eq!(got.get(1, "input/day1.txt", 1), Some("1147"));
+        assert_eq!(got.get(1, "input/day1.txt", 2), Some("6789"));
+        assert_eq!(got.get(9, "inputs/alice/day9.txt", 1), Some("50"));
+        assert_eq!(got.get(9, "inputs/alice/day9.txt", 2), None);
+        assert_eq!(got.get(9, "input/day9.txt", 1), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.set(10, "input/day10.txt", 1, "486");
+        answers.set(2, "odd \"name\".txt", 2, "85513235135");
+
+        assert_eq!(Answers::parse(&answers.to_toml()), Ok(answers));
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.set(3, "input/day3.txt", 1, "17445");
+
+        assert_eq!(
+            answers.check(3, "input/day3.txt", 1, "17445"),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(3, "input/day3.txt", 1, "17444"),
+            Verdict::Fail {
+                expected: "17445".to_string()
+            }
+        );
+        assert_eq!(answers.check(3, "input/day3.txt", 2, "1"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Answers::parse("part1 = \"1\""),
+            Err("1: answer outside of a [dayN.\"<input>\"] table".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[day1.\"a\"]\npart3 = \"1\""),
+            Err("2: unknown key \"part3\"".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[dayx.\"a\"]"),
+            Err("1: expected [dayN.\"<input>\"]".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[day1.\"a\"]\npart1 = 1x"),
+            Err("2: expected a quoted string or integer".to_string())
+        );
+    }
+}
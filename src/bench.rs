@@ -0,0 +1,339 @@
+use std::{
+    fs, hint, io,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{error::ParseError, json::Value, registry::Puzzle};
+
+pub const DEFAULT_BASELINE: &str = "benchmarks.json";
+pub const DEFAULT_ITERATIONS: usize = 20;
+/// Allowed slowdown of the median, in percent, before a phase is flagged.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample");
+        samples.sort_unstable();
+        let p95_index = (samples.len() * 95).div_ceil(100) - 1;
+        Self {
+            min: samples[0],
+            median: samples[(samples.len() - 1) / 2],
+            p95: samples[p95_index],
+        }
+    }
+
+    fn to_json(self) -> Value {
+        Value::Object(vec![
+            (
+                "min_ns".to_string(),
+                Value::from(self.min.as_nanos() as f64),
+            ),
+            (
+                "median_ns".to_string(),
+                Value::from(self.median.as_nanos() as f64),
+            ),
+            (
+                "p95_ns".to_string(),
+                Value::from(self.p95.as_nanos() as f64),
+            ),
+        ])
+    }
+}
+
+pub fn measure<T>(iterations: usize, mut run: impl FnMut() -> T) -> Stats {
+    let samples = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            hint::black_box(run());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(samples)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub day: u8,
+    /// The input's [`Source::key`](crate::input::Source::key), since the
+    /// same day runs at very different speeds on different inputs.
+    pub input: String,
+    /// `("parse" | "part1" | "part2", timings)` in the order they ran.
+    pub phases: Vec<(&'static str, Stats)>,
+    /// Parts whose solver panicked, which have no timings.
+    pub failed: Vec<u8>,
+}
+
+/// Times parsing and each solved part of `parts` separately, on the input
+/// keyed `name`. A part that panics is listed as failed and the rest are
+/// still timed.
+pub fn bench_day(
+    puzzle: &dyn Puzzle,
+    name: &str,
+    input: &str,
+    parts: &[u8],
+    iterations: usize,
+) -> Result<Report, ParseError> {
+    let parsed = puzzle.parse(input)?;
+    let mut phases = vec![("parse", measure(iterations, || puzzle.parse(input)))];
+    let mut failed = Vec::new();
+
+    for &part in parts {
+        let timed = panic::catch_unwind(AssertUnwindSafe(|| {
+            puzzle.solve(parsed.as_ref(), part)?;
+            Some(measure(iterations, || puzzle.solve(parsed.as_ref(), part)))
+        }));
+        let name = if part == 1 { "part1" } else { "part2" };
+        match timed {
+            Ok(Some(stats)) => phases.push((name, stats)),
+            Ok(None) => {}
+            Err(_) => failed.push(part),
+        }
+    }
+
+    Ok(Report {
+        day: puzzle.day(),
+        input: name.to_string(),
+        phases,
+        failed,
+    })
+}
+
+/// How a phase's median compares to the saved baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    pub baseline: Duration,
+    /// Relative change in percent; positive is slower.
+    pub percent: f64,
+    pub regressed: bool,
+}
+
+impl Report {
+    pub fn key(&self) -> String {
+        format!("day{}", self.day)
+    }
+
+    /// Stores each phase in `baseline` under this report's day and input,
+    /// keeping any other phases already saved there.
+    pub fn record(&self, baseline: &mut Value) {
+        let object = |value: Option<&Value>| {
+            value
+                .filter(|value| matches!(value, Value::Object(_)))
+                .cloned()
+                .unwrap_or(Value::Object(Vec::new()))
+        };
+        let mut day = object(baseline.get(&self.key()));
+        let mut saved = object(day.get(&self.input));
+        for (name, stats) in &self.phases {
+            saved.insert(name, stats.to_json());
+        }
+        day.insert(&self.input, saved);
+        baseline.insert(&self.key(), day);
+    }
+
+    /// The saved timings for this report's day and input, if any.
+    pub fn saved<'a>(&self, baseline: &'a Value) -> Option<&'a Value> {
+        baseline.get(&self.key())?.get(&self.input)
+    }
+
+    /// Compares each phase against `baseline`, which holds every saved day
+    /// and input. Phases missing from the baseline get `None`.
+    pub fn compare(&self, baseline: &Value, threshold: f64) -> Vec<Option<Change>> {
+        let saved = self.saved(baseline);
+        self.phases
+            .iter()
+            .map(|(name, stats)| {
+                let nanos = saved?.get(name)?.get("median_ns")?.as_f64()?;
+                let before = Duration::from_nanos(nanos as u64);
+                let percent = if nanos > 0.0 {
+                    (stats.median.as_nanos() as f64 / nanos - 1.0) * 100.0
+                } else {
+                    0.0
+                };
+                Some(Change {
+                    baseline: before,
+                    percent,
+                    regressed: percent > threshold,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Loads the baseline file, treating a missing file as an empty baseline.
+pub fn load_baseline(path: &Path) -> io::Result<Value> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Value::Object(Vec::new())),
+        Err(err) => return Err(err),
+    };
+    crate::json::parse(&text).map_err(|offset| {
+        let message = format!("{}: invalid JSON at byte {offset}", path.display());
+        io::Error::new(io::ErrorKind::InvalidData, message)
+    })
+}
+
+/// Stores `report` in `baseline` and writes the whole file.
+pub fn save_baseline(path: &Path, mut baseline: Value, report: &Report) -> io::Result<()> {
+    report.record(&mut baseline);
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, baseline.to_pretty_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Solution, day_1};
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = (1..=20).rev().map(ms).collect();
+        let got = Stats::from_samples(samples);
+
+        assert_eq!(
+            got,
+            Stats {
+                min: ms(1),
+                median: ms(10),
+                p95: ms(19)
+            }
+        );
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let got = Stats::from_samples(vec![ms(4)]);
+        assert_eq!((got.min, got.median, got.p95), (ms(4), ms(4), ms(4)));
+    }
+
+    #[test]
+    fn test_compare() {
+        let stats = |median| Stats {
+            min: ms(1),
+            median: ms(median),
+            p95: ms(30),
+        };
+        let before = Report {
+            day: 8,
+            input: "input/day8.txt".to_string(),
+            phases: vec![("parse", stats(10)), ("part1", stats(20))],
+            failed: Vec::new(),
+        };
+        let mut baseline = Value::Object(Vec::new());
+        before.record(&mut baseline);
+
+        let after = Report {
+            day: 8,
+            input: "input/day8.txt".to_string(),
+            phases: vec![
+                ("parse", stats(10)),
+                ("part1", stats(25)),
+                ("part2", stats(5)),
+            ],
+            failed: Vec::new(),
+        };
+        let got = after.compare(&baseline, 10.0);
+
+        assert_eq!(got[0].map(|change| change.regressed), Some(false));
+        assert_eq!(got[1].map(|change| change.percent), Some(25.0));
+        assert_eq!(got[1].map(|change| change.regressed), Some(true));
+        assert_eq!(got[2], None);
+
+        let example = Report {
+            input: "examples/day8/example.txt".to_string(),
+            ..after
+        };
+        assert_eq!(example.compare(&baseline, 10.0), [None, None, None]);
+    }
+
+    #[test]
+    fn test_record_keeps_other_phases() {
+        let stats = |median| Stats {
+            min: ms(1),
+            median: ms(median),
+            p95: ms(30),
+        };
+        let report = |phases| Report {
+            day: 8,
+            input: "input/day8.txt".to_string(),
+            phases,
+            failed: Vec::new(),
+        };
+        let mut baseline = Value::Object(Vec::new());
+        report(vec![
+            ("parse", stats(10)),
+            ("part1", stats(20)),
+            ("part2", stats(30)),
+        ])
+        .record(&mut baseline);
+        report(vec![("parse", stats(12)), ("part1", stats(25))]).record(&mut baseline);
+
+        let got = report(vec![
+            ("parse", stats(12)),
+            ("part1", stats(25)),
+            ("part2", stats(30)),
+        ])
+        .compare(&baseline, 10.0);
+        assert!(
+            got.iter()
+                .all(|change| change.is_some_and(|change| change.percent == 0.0))
+        );
+    }
+
+    #[test]
+    fn test_bench_day_phases() {
+        let puzzle = crate::registry::find(10).unwrap();
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+        let report = bench_day(puzzle, "example", input, &[1, 2], 3).unwrap();
+
+        let names: Vec<_> = report.phases.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, ["parse", "part1"]);
+        assert!(report.failed.is_empty());
+    }
+
+    /// Day 1 with a first part that always panics.
+    struct Panicking;
+
+    impl Solution for Panicking {
+        const DAY: u8 = 1;
+
+        type Input = Vec<day_1::Turn>;
+        type Answer1 = i32;
+        type Answer2 = i64;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input.lines().map(day_1::parse_turn).collect()
+        }
+
+        fn part_one(_input: &Self::Input) -> Self::Answer1 {
+            panic!("part one is broken")
+        }
+
+        fn part_two(input: &Self::Input) -> Option<Self::Answer2> {
+            Some(day_1::get_pass_complex(input))
+        }
+    }
+
+    #[test]
+    fn test_bench_day_keeps_parts_after_a_panic() {
+        let report = bench_day(&Panicking, "example", "L68\nR48\n", &[1, 2], 3).unwrap();
+
+        let names: Vec<_> = report.phases.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, ["parse", "part2"]);
+        assert_eq!(report.failed, [1]);
+    }
+}
@@ -0,0 +1,5 @@
+use std::{env, process};
+
+fn main() {
+    process::exit(advent_of_code::runner::main(env::args().skip(1)));
+}
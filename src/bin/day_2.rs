@@ -1,101 +1,8 @@
-use std::fs::read_to_string;
+use std::process;
 
 fn main() {
-    let input = read_to_string("input/day2.txt").expect("Should have been able to read file");
-    println!("sum 1: {}", id_sum(&input, invalid_id));
-    println!("sum 2: {}", id_sum(&input, invalid_id_two));
-}
-
-fn id_sum(ranges: &str, invalid_func: fn(i64) -> bool) -> i64 {
-    ranges
-        .trim_end()
-        .split(",")
-        .map(|range| {
-            let (start, end) = range.split_once('-').expect("Should have '-' in range.");
-            let start: i64 = start
-                .parse()
-                .unwrap_or_else(|_| panic!("ERROR: could not parse start as i64: {start:?}"));
-            let end: i64 = end
-                .parse()
-                .unwrap_or_else(|_| panic!("ERROR: could not parse end as i64: {end:?}"));
-
-            (start..=end).filter(|&id| invalid_func(id)).sum::<i64>()
-        })
-        .sum()
-}
-
-fn invalid_id_two(id: i64) -> bool {
-    let str = id.to_string();
-    let digits = str.len();
-
-    (1..=(digits / 2)).any(|size| {
-        let first = &str.as_bytes()[..size];
-        str.as_bytes().chunks(size).all(|chunk| chunk == first)
-    })
-}
-
-fn invalid_id(id: i64) -> bool {
-    let digits = id.checked_ilog10().unwrap_or(0) + 1;
-    let val = 10_i64.pow(digits / 2);
-
-    id / val == id % val
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_invalid_id_invalid_two_short() {
-        assert!(invalid_id_two(11), "expected invalid got valid");
-    }
-
-    #[test]
-    fn test_invalid_id_invalid_two_long() {
-        assert!(invalid_id_two(121212), "expected invalid got valid")
-    }
-
-    #[test]
-    fn test_invalid_id_valid_two_long() {
-        assert!(!invalid_id_two(12345), "expected invalid got valid")
-    }
-
-    #[test]
-    fn test_id_sum_simple() {
-        let id_ranges = "11-22";
-        assert_eq!(id_sum(id_ranges, invalid_id), 33)
-    }
-
-    #[test]
-    fn test_id_sum_multiple() {
-        let id_ranges = "11-22,33-44";
-        assert_eq!(id_sum(id_ranges, invalid_id), 110)
-    }
-
-    #[test]
-    fn test_id_sum_example() {
-        let id_ranges = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        assert_eq!(id_sum(id_ranges, invalid_id), 1227775554)
-    }
-
-    #[test]
-    fn test_id_sum_other_func_example() {
-        let id_ranges = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        assert_eq!(id_sum(id_ranges, invalid_id_two), 4174379265)
-    }
-
-    #[test]
-    fn test_invalid_id_invalid_short() {
-        assert!(invalid_id(11))
-    }
-
-    #[test]
-    fn test_invalid_id_invalid_long() {
-        assert!(invalid_id(1234577712345777))
-    }
-
-    #[test]
-    fn test_invalid_id_valid() {
-        assert!(!invalid_id(123456))
-    }
+    process::exit(advent_of_code::runner::main([
+        "run".to_string(),
+        "2".to_string(),
+    ]));
 }
@@ -0,0 +1,275 @@
+use std::{fmt::Display, io, time::Duration};
+
+use crate::{config::Config, http, submissions::Account};
+
+const USER_AGENT: &str = "github.com/elasticspoon/aoc_2025 (advent_of_code runner)";
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Io(io::Error),
+    /// The site answered with a non-success status.
+    Status {
+        status: u16,
+        body: String,
+    },
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session cookie; set `session` in the config or {}",
+                crate::config::SESSION_VAR
+            ),
+            ClientError::Io(err) => write!(f, "request failed: {err}"),
+            ClientError::Status { status, body } => {
+                write!(f, "server returned {status}: {}", body.trim())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        ClientError::Io(err)
+    }
+}
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too soon after a previous answer.
+    Wait(Duration),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    Unrecognized(String),
+}
+
+impl Display for Reply {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reply::Correct => write!(f, "correct"),
+            Reply::TooHigh => write!(f, "wrong: too high"),
+            Reply::TooLow => write!(f, "wrong: too low"),
+            Reply::Wrong => write!(f, "wrong"),
+            Reply::Wait(wait) => write!(f, "too soon: wait {}s", wait.as_secs()),
+            Reply::WrongLevel => write!(f, "part already solved or not unlocked"),
+            Reply::Unrecognized(text) => write!(f, "unrecognized reply: {text}"),
+        }
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    year: u16,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, ClientError> {
+        let session = config.session.clone().ok_or(ClientError::MissingSession)?;
+        Ok(Self {
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            year: config.year,
+            session,
+        })
+    }
+
+    /// The year and session that submissions through this client count for.
+    pub fn account(&self) -> Account {
+        Account::new(self.year, &self.session)
+    }
+
+    pub fn fetch_input(&self, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/{}/day/{day}/input", self.base_url, self.year);
+        let cookie = format!("session={}", self.session);
+        let response = http::get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])?;
+        match response.status {
+            200 => Ok(response.body),
+            status => Err(ClientError::Status {
+                status,
+                body: response.body,
+            }),
+        }
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Reply, ClientError> {
+        let url = format!("{}/{}/day/{day}/answer", self.base_url, self.year);
+        let cookie = format!("session={}", self.session);
+        let level = part.to_string();
+        let response = http::post_form(
+            &url,
+            &[("Cookie", &cookie), ("User-Agent", USER_AGENT)],
+            &[("level", &level), ("answer", answer)],
+        )?;
+        match response.status {
+            200 => Ok(parse_reply(&response.body)),
+            status => Err(ClientError::Status {
+                status,
+                body: response.body,
+            }),
+        }
+    }
+}
+
+/// Reads the verdict out of the page returned after submitting an answer.
+pub fn parse_reply(page: &str) -> Reply {
+    let text = page.split_once("<article>").map_or(page, |(_, article)| {
+        article.split("</article>").next().unwrap_or(article)
+    });
+    let text = strip_tags(text);
+
+    if text.contains("That's the right answer") {
+        Reply::Correct
+    } else if text.contains("your answer is too high") {
+        Reply::TooHigh
+    } else if text.contains("your answer is too low") {
+        Reply::TooLow
+    } else if text.contains("That's not the right answer") {
+        Reply::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        Reply::Wait(parse_wait(&text).unwrap_or_default())
+    } else if text.contains("You don't seem to be solving the right level") {
+        Reply::WrongLevel
+    } else {
+        Reply::Unrecognized(text.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for char in html.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            char if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Reads durations like "You have 1m 3s left to wait" or "You have 34s left".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (amount, _) = rest.split_once(" left")?;
+    let mut seconds = 0;
+    for part in amount.split_whitespace() {
+        let (num, unit) = part.split_at(part.find(|char: char| !char.is_ascii_digit())?);
+        let num: u64 = num.parse().ok()?;
+        seconds += match unit {
+            "h" => num * 3600,
+            "m" => num * 60,
+            "s" => num,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stub;
+
+    fn client(base_url: &str) -> Client {
+        let config = Config {
+            session: Some("cafe".to_string()),
+            base_url: base_url.to_string(),
+            year: 2025,
+        };
+        Client::new(&config).unwrap()
+    }
+
+    fn page(message: &str) -> String {
+        format!("<html><main><article><p>{message}</p></article></main></html>")
+    }
+
+    #[test]
+    fn test_missing_session() {
+        let config = Config::default();
+        assert!(matches!(
+            Client::new(&config),
+            Err(ClientError::MissingSession)
+        ));
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = stub::serve(200, "L68\nL30\n");
+        let got = client(&base_url).fetch_input(1).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(got, "L68\nL30\n");
+        assert!(request.starts_with("GET /2025/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=cafe\r\n"));
+    }
+
+    #[test]
+    fn test_fetch_input_not_found() {
+        let (base_url, server) = stub::serve(404, "Please don't repeatedly request this endpoint");
+        let got = client(&base_url).fetch_input(25);
+        server.join().unwrap();
+
+        assert!(matches!(got, Err(ClientError::Status { status: 404, .. })));
+    }
+
+    #[test]
+    fn test_submit() {
+        let body =
+            page("That's not the right answer; your answer is too high.  If you're stuck...");
+        let (base_url, server) = stub::serve(200, &body);
+        let got = client(&base_url).submit(8, 2, "7893123992").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(got, Reply::TooHigh);
+        assert!(request.starts_with("POST /2025/day/8/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("level=2&answer=7893123992"));
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        let cases = [
+            (
+                "That's the right answer!  You are <em>one gold star</em> closer.",
+                Reply::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Reply::TooLow,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure...",
+                Reply::Wrong,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 3s left to wait.",
+                Reply::Wait(Duration::from_secs(63)),
+            ),
+            (
+                "You gave an answer too recently.  You have 34s left to wait.",
+                Reply::Wait(Duration::from_secs(34)),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Reply::WrongLevel,
+            ),
+        ];
+        for (message, want) in cases {
+            assert_eq!(parse_reply(&page(message)), want, "{message}");
+        }
+        assert_eq!(
+            parse_reply("<p>Something new</p>"),
+            Reply::Unrecognized("Something new".to_string())
+        );
+    }
+}
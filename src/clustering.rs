@@ -0,0 +1,336 @@
+use std::fmt::{Display, Write};
+
+use crate::{
+    json::Value,
+    point::{Coordinate, Metric, Point},
+    spatial::pairs_by_distance,
+    union_find::UnionFind,
+};
+
+/// A connection between two points by index, with its distance as
+/// [`Metric::distance`] measures it, which is squared for
+/// [`Metric::Euclidean`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Edge<T> {
+    pub dist: T,
+    pub first: usize,
+    pub second: usize,
+}
+
+/// The minimum spanning tree of a set of points by the given metric, found
+/// with Kruskal's algorithm over the closest pairs. Edges are kept in the
+/// order they were added, closest first, with ties in the order day 8
+/// connects them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanningTree<'a, const N: usize, T> {
+    pub points: &'a [Point<N, T>],
+    pub metric: Metric,
+    pub edges: Vec<Edge<T>>,
+}
+
+impl<'a, const N: usize, T: Coordinate> SpanningTree<'a, N, T> {
+    pub fn new(points: &'a [Point<N, T>], metric: Metric) -> Self {
+        let mut sets = UnionFind::new(points.len());
+        let mut pairs = pairs_by_distance(points, metric);
+        let mut edges = Vec::new();
+        while sets.components() > 1 {
+            let Some((first, second)) = pairs.next() else {
+                break;
+            };
+            if sets.union(first, second) {
+                edges.push(Edge {
+                    dist: metric.distance(&points[first], &points[second]),
+                    first,
+                    second,
+                });
+            }
+        }
+        Self {
+            points,
+            metric,
+            edges,
+        }
+    }
+}
+
+impl<const N: usize, T: Coordinate + Display> SpanningTree<'_, N, T> {
+    /// `{"points": [[x, y, ...], ...], "edges": [{"first", "second",
+    /// "distance"}, ...]}`, with edges naming points by index. Distances are
+    /// straight-line lengths, by [`Metric::length`].
+    pub fn to_json(&self) -> Value {
+        let edges = self
+            .edges
+            .iter()
+            .map(|edge| {
+                Value::Object(vec![
+                    ("first".to_string(), Value::from(edge.first)),
+                    ("second".to_string(), Value::from(edge.second)),
+                    ("distance".to_string(), length(self.metric, edge.dist)),
+                ])
+            })
+            .collect();
+        Value::Object(vec![
+            ("points".to_string(), points_json(self.points)),
+            ("edges".to_string(), Value::Array(edges)),
+        ])
+    }
+
+    /// An undirected Graphviz graph with a node per point, labelled with its
+    /// coordinates, and edges labelled with their lengths.
+    pub fn to_dot(&self) -> String {
+        let mut dot = "graph spanning_tree {\n".to_string();
+        write_points(&mut dot, self.points);
+        for edge in &self.edges {
+            let Edge {
+                dist,
+                first,
+                second,
+            } = edge;
+            let length = self.metric.length(dist.to_f64());
+            writeln!(dot, "  {first} -- {second} [label=\"{length}\"];")
+                .expect("writing to a String cannot fail");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// One step of single-linkage clustering: the two closest clusters joined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Merge<T> {
+    /// The clusters joined, smaller number first. Points are clusters
+    /// `0..n`, and the cluster made by merge `i` is `n + i`, as in SciPy's
+    /// linkage matrices.
+    pub left: usize,
+    pub right: usize,
+    /// The distance between the closest points of the two clusters, as
+    /// [`Metric::distance`] measures it, which is squared for
+    /// [`Metric::Euclidean`].
+    pub dist: T,
+    /// How many points the merged cluster holds.
+    pub size: usize,
+}
+
+/// The single-linkage dendrogram of a set of points: every merge, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dendrogram<'a, const N: usize, T> {
+    pub points: &'a [Point<N, T>],
+    pub metric: Metric,
+    pub merges: Vec<Merge<T>>,
+}
+
+impl<'a, const N: usize, T: Coordinate> Dendrogram<'a, N, T> {
+    /// Single linkage joins clusters along the spanning tree's edges, in the
+    /// order Kruskal's algorithm added them.
+    pub fn new(tree: &SpanningTree<'a, N, T>) -> Self {
+        let len = tree.points.len();
+        let mut sets = UnionFind::new(len);
+        // The cluster number of each set, by its representative.
+        let mut cluster: Vec<usize> = (0..len).collect();
+        let mut merges = Vec::new();
+        for (index, edge) in tree.edges.iter().enumerate() {
+            let left = cluster[sets.find(edge.first)];
+            let right = cluster[sets.find(edge.second)];
+            sets.union(edge.first, edge.second);
+            let root = sets.find(edge.first);
+            cluster[root] = len + index;
+            merges.push(Merge {
+                left: left.min(right),
+                right: left.max(right),
+                dist: edge.dist,
+                size: sets.size_of(root),
+            });
+        }
+        Self {
+            points: tree.points,
+            metric: tree.metric,
+            merges,
+        }
+    }
+}
+
+impl<const N: usize, T: Coordinate + Display> Dendrogram<'_, N, T> {
+    /// `{"points": [[x, y, ...], ...], "merges": [{"left", "right",
+    /// "distance", "size"}, ...]}`, numbering clusters as in [`Merge`].
+    /// Distances are straight-line lengths, by [`Metric::length`].
+    pub fn to_json(&self) -> Value {
+        let merges = self
+            .merges
+            .iter()
+            .map(|merge| {
+                Value::Object(vec![
+                    ("left".to_string(), Value::from(merge.left)),
+                    ("right".to_string(), Value::from(merge.right)),
+                    ("distance".to_string(), length(self.metric, merge.dist)),
+                    ("size".to_string(), Value::from(merge.size)),
+                ])
+            })
+            .collect();
+        Value::Object(vec![
+            ("points".to_string(), points_json(self.points)),
+            ("merges".to_string(), Value::Array(merges)),
+        ])
+    }
+
+    /// A Graphviz tree with the points as leaves, each merge pointing at the
+    /// two clusters it joined and labelled with its length and size.
+    pub fn to_dot(&self) -> String {
+        let mut dot = "digraph dendrogram {\n".to_string();
+        write_points(&mut dot, self.points);
+        for (index, merge) in self.merges.iter().enumerate() {
+            let node = self.points.len() + index;
+            let Merge {
+                left,
+                right,
+                dist,
+                size,
+            } = merge;
+            let length = self.metric.length(dist.to_f64());
+            writeln!(dot, "  {node} [label=\"{length} ({size})\"];")
+                .expect("writing to a String cannot fail");
+            writeln!(dot, "  {node} -> {left};").expect("writing to a String cannot fail");
+            writeln!(dot, "  {node} -> {right};").expect("writing to a String cannot fail");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn number<T: Coordinate>(value: T) -> Value {
+    Value::from(value.to_f64())
+}
+
+fn length<T: Coordinate>(metric: Metric, dist: T) -> Value {
+    Value::from(metric.length(dist.to_f64()))
+}
+
+fn points_json<const N: usize, T: Coordinate>(points: &[Point<N, T>]) -> Value {
+    let coords = |point: &Point<N, T>| Value::Array(point.0.iter().map(|&c| number(c)).collect());
+    Value::Array(points.iter().map(coords).collect())
+}
+
+fn write_points<const N: usize, T: Display>(dot: &mut String, points: &[Point<N, T>]) {
+    for (index, point) in points.iter().enumerate() {
+        writeln!(dot, "  {index} [label=\"{point}\"];").expect("writing to a String cannot fail");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate::Rng, point::P2};
+
+    /// Prim's algorithm over every pair, for the total the tree should have.
+    fn prim_total(points: &[P2<u64>], metric: Metric) -> u64 {
+        let mut best = vec![u64::MAX; points.len()];
+        let mut done = vec![false; points.len()];
+        let mut total = 0;
+        best[0] = 0;
+        for _ in 0..points.len() {
+            let next = (0..points.len())
+                .filter(|&index| !done[index])
+                .min_by_key(|&index| best[index])
+                .unwrap();
+            done[next] = true;
+            total += best[next];
+            for other in 0..points.len() {
+                let dist = metric.distance(&points[next], &points[other]);
+                best[other] = best[other].min(dist);
+            }
+        }
+        total
+    }
+
+    #[test]
+    fn test_spanning_tree_is_minimal() {
+        let mut rng = Rng::new(3);
+        for metric in [Metric::Manhattan, Metric::Chebyshev, Metric::Euclidean] {
+            for len in [1, 2, 5, 30, 80] {
+                let points: Vec<P2<u64>> = (0..len)
+                    .map(|_| Point([rng.range(0..=50), rng.range(0..=50)]))
+                    .collect();
+                let tree = SpanningTree::new(&points, metric);
+                assert_eq!(tree.edges.len(), len - 1);
+                let total: u64 = tree.edges.iter().map(|edge| edge.dist).sum();
+                assert_eq!(
+                    total,
+                    prim_total(&points, metric),
+                    "{len} points by {metric}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_dendrogram() {
+        let points = [Point([0, 0]), Point([10, 0]), Point([1, 0]), Point([4, 0])];
+        let tree = SpanningTree::new(&points, Metric::Euclidean);
+        let dendrogram = Dendrogram::new(&tree);
+        let merge = |left, right, dist, size| Merge {
+            left,
+            right,
+            dist,
+            size,
+        };
+        assert_eq!(
+            dendrogram.merges,
+            [merge(0, 2, 1, 2), merge(3, 4, 9, 3), merge(1, 5, 36, 4)]
+        );
+    }
+
+    #[test]
+    fn test_ties_follow_point_order() {
+        // Both unit pairs tie; the one at the lesser points comes first,
+        // whatever the order of the indices.
+        let points = [Point([5, 0]), Point([0, 0]), Point([1, 0]), Point([6, 0])];
+        let tree = SpanningTree::new(&points, Metric::Euclidean);
+        let pairs: Vec<_> = tree
+            .edges
+            .iter()
+            .map(|edge| (edge.first, edge.second))
+            .collect();
+        assert_eq!(pairs, [(1, 2), (0, 3), (0, 2)]);
+    }
+
+    #[test]
+    fn test_exports() {
+        let points = [Point([0, 0]), Point([4, 4]), Point([0, 1])];
+        let tree = SpanningTree::new(&points, Metric::Euclidean);
+        assert_eq!(
+            tree.to_json().to_string(),
+            r#"{"points":[[0,0],[4,4],[0,1]],"edges":[{"first":0,"second":2,"distance":1},{"first":1,"second":2,"distance":5}]}"#
+        );
+        assert_eq!(
+            tree.to_dot(),
+            "graph spanning_tree {
+  0 [label=\"0,0\"];
+  1 [label=\"4,4\"];
+  2 [label=\"0,1\"];
+  0 -- 2 [label=\"1\"];
+  1 -- 2 [label=\"5\"];
+}
+"
+        );
+
+        let dendrogram = Dendrogram::new(&tree);
+        assert_eq!(
+            dendrogram.to_json().to_string(),
+            r#"{"points":[[0,0],[4,4],[0,1]],"merges":[{"left":0,"right":2,"distance":1,"size":2},{"left":1,"right":3,"distance":5,"size":3}]}"#
+        );
+        assert_eq!(
+            dendrogram.to_dot(),
+            "digraph dendrogram {
+  0 [label=\"0,0\"];
+  1 [label=\"4,4\"];
+  2 [label=\"0,1\"];
+  3 [label=\"1 (2)\"];
+  3 -> 0;
+  3 -> 2;
+  4 [label=\"5 (3)\"];
+  4 -> 1;
+  4 -> 3;
+}
+"
+        );
+    }
+}
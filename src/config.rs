@@ -0,0 +1,125 @@
+use std::{env, fs, io, path::Path};
+
+use crate::answers;
+
+pub const DEFAULT_CONFIG: &str = "aoc.toml";
+/// Environment variable that overrides the session cookie from the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u16 = 2025;
+
+/// Settings for talking to the puzzle site, read from a TOML file like:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// year = 2025
+/// base_url = "https://adventofcode.com"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub year: u16,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            year: DEFAULT_YEAR,
+        }
+    }
+}
+
+impl Config {
+    /// Loads `path`, treating a missing file as all defaults, then applies
+    /// the session from the environment if it is set.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| {
+                let message = format!("{}:{err}", path.display());
+                io::Error::new(io::ErrorKind::InvalidData, message)
+            })?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(err) => return Err(err),
+        };
+
+        if let Some(session) = env::var(SESSION_VAR)
+            .ok()
+            .filter(|session| !session.is_empty())
+        {
+            config.session = Some(session);
+        }
+        Ok(config)
+    }
+
+    /// Errors carry the 1-based line number, as `"<line>: <message>"`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let line_number = index + 1;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("{line_number}: expected key = value"))?;
+            let value = answers::parse_value(value.trim()).ok_or(format!(
+                "{line_number}: expected a quoted string or integer"
+            ))?;
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value.trim_end_matches('/').to_string(),
+                "year" => {
+                    config.year = value
+                        .parse()
+                        .map_err(|_| format!("{line_number}: expected a year, got {value:?}"))?
+                }
+                key => return Err(format!("{line_number}: unknown key {key:?}")),
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = r#"
+# personal settings, do not commit
+session = "abc123"
+year = 2024
+base_url = "http://127.0.0.1:8080/"
+"#;
+        let want = Config {
+            session: Some("abc123".to_string()),
+            base_url: "http://127.0.0.1:8080".to_string(),
+            year: 2024,
+        };
+
+        assert_eq!(Config::parse(text), Ok(want));
+    }
+
+    #[test]
+    fn test_parse_defaults() {
+        assert_eq!(Config::parse(""), Ok(Config::default()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Config::parse("token = \"x\""),
+            Err("1: unknown key \"token\"".to_string())
+        );
+        assert_eq!(
+            Config::parse("\nyear = \"soon\""),
+            Err("2: expected a year, got \"soon\"".to_string())
+        );
+    }
+}
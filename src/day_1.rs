@@ -0,0 +1,273 @@
+use crate::{Solution, error::ParseError};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Turn>;
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| parse_turn(line).map_err(|err| err.at_line(index + 1)))
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input) -> Self::Answer1 {
+        get_pass(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Self::Answer2> {
+        Some(get_pass_complex(input))
+    }
+
+    fn naive_part_one(input: &str, _options: &[(String, String)]) -> Option<Self::Answer1> {
+        Some(count_zeros_by_click(input).0)
+    }
+
+    fn naive_part_two(input: &str, _options: &[(String, String)]) -> Option<Self::Answer2> {
+        Some(count_zeros_by_click(input).1)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Turn {
+    Left(i32),
+    Right(i32),
+}
+
+pub fn get_pass(turns: &[Turn]) -> i32 {
+    let mut loc = 50;
+    let mut times = 0;
+    for &turn in turns {
+        let (_, rem) = rotate(loc, turn);
+        loc = rem;
+
+        if rem == 0 {
+            times += 1;
+        }
+    }
+
+    times
+}
+
+/// Counts in `i64`, since enough long turns pass zero more than `i32::MAX`
+/// times between them.
+pub fn get_pass_complex(turns: &[Turn]) -> i64 {
+    let mut loc = 50;
+    let mut times = 0;
+    for &turn in turns {
+        let (zero_count, place) = rotate(loc, turn);
+        loc = place;
+        times += i64::from(zero_count);
+    }
+
+    times
+}
+
+pub fn parse_turn(turn: &str) -> Result<Turn, ParseError> {
+    let Some(first_letter) = turn.chars().next() else {
+        return Err(ParseError::in_line(
+            Day1::DAY,
+            turn,
+            turn,
+            "a turn like 'L68'",
+        ));
+    };
+    let (direction, distance) = turn.split_at(first_letter.len_utf8());
+    let num = distance
+        .parse::<u32>()
+        .ok()
+        .and_then(|num| i32::try_from(num).ok())
+        .ok_or_else(|| ParseError::in_line(Day1::DAY, turn, distance, "a turn distance"));
+    match first_letter {
+        'L' => Ok(Turn::Left(num?)),
+        'R' => Ok(Turn::Right(num?)),
+        _ => Err(ParseError::in_line(
+            Day1::DAY,
+            turn,
+            direction,
+            "'L' or 'R'",
+        )),
+    }
+}
+
+/// Turns the dial one click at a time. Returns how many turns stop on zero
+/// and how many clicks land on it.
+pub fn count_zeros_by_click(input: &str) -> (i32, i64) {
+    let mut position = 50;
+    let (mut stops, mut clicks) = (0, 0);
+    for line in input.lines() {
+        let (direction, distance) = line.split_at(1);
+        let step = if direction == "L" { 99 } else { 1 };
+        for _ in 0..distance.parse::<i32>().unwrap() {
+            position = (position + step) % 100;
+            if position == 0 {
+                clicks += 1;
+            }
+        }
+        if position == 0 {
+            stops += 1;
+        }
+    }
+    (stops, clicks)
+}
+
+/// Returns how many times the dial passes or lands on zero, and where it stops.
+/// Works in `i64`, since a turn of up to `i32::MAX` clicks overflows `i32`.
+pub fn rotate(initial: i32, turn: Turn) -> (i32, i32) {
+    let initial = i64::from(initial);
+    let (turns, rem) = match turn {
+        Turn::Left(num) => {
+            let new_pos = initial - i64::from(num);
+            let mut turns = (new_pos / 100).abs();
+            let rem = new_pos.rem_euclid(100);
+            if initial > 0 && new_pos <= 0 {
+                turns += 1;
+            }
+            (turns, rem)
+        }
+        Turn::Right(num) => {
+            let new_pos = initial + i64::from(num);
+            let turns = new_pos / 100;
+            let rem = new_pos % 100;
+            (turns, rem)
+        }
+    };
+    // At most `i32::MAX / 100 + 1` turns, and the dial stays below 100.
+    (turns as i32, rem as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day1/example.txt");
+
+    #[test]
+    fn test_get_pass_simple() {
+        let moves = EXAMPLE;
+        let got = get_pass(&Day1::parse(moves).unwrap());
+        assert_eq!(got, 3);
+    }
+
+    #[test]
+    fn test_get_pass_complex() {
+        let moves = EXAMPLE;
+        let got = get_pass_complex(&Day1::parse(moves).unwrap());
+        assert_eq!(got, 6);
+    }
+
+    #[test]
+    fn test_get_pass_complex_many_long_turns() {
+        let turns = vec![Turn::Right(i32::MAX); 101];
+        let got = get_pass_complex(&turns);
+        assert_eq!(got, 2_168_958_483);
+    }
+
+    #[test]
+    fn test_parse_turn() {
+        assert_eq!(parse_turn("L68"), Ok(Turn::Left(68)));
+        assert_eq!(parse_turn("R5"), Ok(Turn::Right(5)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Day1::parse("L68\nX30"),
+            Err(ParseError::new(1, 2, 1, "X", "'L' or 'R'"))
+        );
+        assert_eq!(
+            Day1::parse("L68\nL30\nR4x"),
+            Err(ParseError::new(1, 3, 2, "4x", "a turn distance"))
+        );
+        assert_eq!(
+            Day1::parse("L68\n\nR4"),
+            Err(ParseError::new(1, 2, 1, "", "a turn like 'L68'"))
+        );
+    }
+
+    #[test]
+    fn test_rotate_left() {
+        let turn = "L32";
+        let (turns, loc) = rotate(50, parse_turn(turn).unwrap());
+
+        assert_eq!(loc, 18);
+        assert_eq!(turns, 0);
+    }
+
+    #[test]
+    fn test_rotate_right() {
+        let turn = "R32";
+        let (turns, loc) = rotate(50, parse_turn(turn).unwrap());
+
+        assert_eq!(loc, 82);
+        assert_eq!(turns, 0);
+    }
+
+    #[test]
+    fn test_rotate_right_exact() {
+        let turn = "R50";
+        let (turns, loc) = rotate(50, parse_turn(turn).unwrap());
+
+        assert_eq!(loc, 0);
+        assert_eq!(turns, 1);
+    }
+
+    #[test]
+    fn test_rotate_left_exact_100() {
+        let turn = "L101";
+        let (turns, loc) = rotate(1, parse_turn(turn).unwrap());
+
+        assert_eq!(loc, 0);
+        assert_eq!(turns, 2);
+    }
+    #[test]
+    fn test_rotate_left_exact_zero() {
+        let turn = "L50";
+        let (turns, loc) = rotate(50, parse_turn(turn).unwrap());
+
+        assert_eq!(loc, 0);
+        assert_eq!(turns, 1);
+    }
+
+    #[test]
+    fn test_rotate_left_over() {
+        let turn = "L180";
+        let (turns, loc) = rotate(50, parse_turn(turn).unwrap());
+
+        assert_eq!(loc, 70);
+        assert_eq!(turns, 2);
+    }
+
+    #[test]
+    fn test_rotate_left_from_zero() {
+        let turn = "L20";
+        let (turns, loc) = rotate(0, parse_turn(turn).unwrap());
+
+        assert_eq!(loc, 80);
+        assert_eq!(turns, 0);
+    }
+
+    #[test]
+    fn test_rotate_right_over() {
+        let turn = "R320";
+        let (turns, loc) = rotate(50, parse_turn(turn).unwrap());
+
+        assert_eq!(loc, 70);
+        assert_eq!(turns, 3);
+    }
+
+    #[test]
+    fn test_rotate_longest_turns() {
+        let right = rotate(50, parse_turn("R2147483647").unwrap());
+        assert_eq!(right, (21474836, 97));
+
+        let left = rotate(50, parse_turn("L2147483647").unwrap());
+        assert_eq!(left, (21474836, 3));
+    }
+}
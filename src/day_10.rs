@@ -0,0 +1,279 @@
+use std::collections::HashSet;
+
+use crate::{Solution, error::ParseError};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        convert_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::Answer1 {
+        min_press_sum(input)
+    }
+
+    fn naive_part_one(input: &str, _options: &[(String, String)]) -> Option<Self::Answer1> {
+        let machines = convert_input(input).unwrap();
+        Some(
+            machines
+                .iter()
+                .map(|(target, buttons, _)| min_presses_naive(*target, buttons))
+                .sum(),
+        )
+    }
+}
+
+/// Tries every subset of buttons, since pressing one twice undoes it.
+pub fn min_presses_naive(target: u16, buttons: &[u16]) -> usize {
+    (0_u32..1 << buttons.len())
+        .filter(|subset| {
+            let lights = buttons
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| subset & (1 << index) != 0)
+                .fold(0, |lights, (_, button)| lights ^ button);
+            lights == target
+        })
+        .map(|subset| subset.count_ones() as usize)
+        .min()
+        .expect("some set of buttons should light the target")
+}
+
+/// Target light bitmask, button bitmasks and joltage requirements.
+pub type Machine = (u16, Vec<u16>, Vec<u16>);
+
+pub fn min_press_sum(machines: &[Machine]) -> usize {
+    machines
+        .iter()
+        .map(|(target, buttons, _)| {
+            min_presses(*target, buttons).expect("parsing checks that every target can be lit")
+        })
+        .sum()
+}
+
+/// The fewest presses that light exactly `target`, if any do.
+pub fn min_presses(target: u16, buttons: &[u16]) -> Option<usize> {
+    if target == 0 {
+        return Some(0);
+    }
+    let mut tested_combos: HashSet<u16> = HashSet::from([0]);
+    let mut loop_count = 1;
+
+    loop {
+        let mut new_combos = HashSet::new();
+        for &button in buttons.iter() {
+            for &tested in tested_combos.clone().iter() {
+                let new_combo = tested ^ button;
+                if new_combo == target {
+                    return Some(loop_count);
+                } else {
+                    new_combos.insert(new_combo);
+                }
+            }
+        }
+        loop_count += 1;
+        let tested = tested_combos.len();
+        tested_combos.extend(new_combos);
+        if tested_combos.len() == tested {
+            return None;
+        }
+    }
+}
+
+pub fn convert_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_machine(line).map_err(|err| err.at_line(index + 1)))
+        .collect()
+}
+
+fn parse_machine(line: &str) -> Result<Machine, ParseError> {
+    let error = |part: &str, expected: &str| ParseError::in_line(Day10::DAY, line, part, expected);
+
+    if !line.starts_with('[') {
+        return Err(error(line.get(..1).unwrap_or(line), "'['"));
+    }
+    let sb_end = line.find(']').ok_or_else(|| error(line, "a ']'"))?;
+    let cb_start = line
+        .find('{')
+        .filter(|&cb_start| cb_start > sb_end)
+        .ok_or_else(|| error(&line[sb_end + 1..], "a '{' after the lights"))?;
+    if !line.ends_with('}') {
+        return Err(error(&line[cb_start..], "a '}' at the end of the line"));
+    }
+
+    let light_diagram = &line[1..sb_end];
+    let light_count = light_diagram.chars().count();
+    if light_count > 16 {
+        return Err(error(light_diagram, "at most 16 lights"));
+    }
+    let light_diagram = light_diagram
+        .char_indices()
+        .enumerate()
+        .filter_map(|(index, (offset, char))| match char {
+            '#' => Some(Ok(index as u8)),
+            '.' => None,
+            _ => Some(Err(error(
+                &light_diagram[offset..offset + char.len_utf8()],
+                "'#' or '.'",
+            ))),
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+    let light_diagram = indicies_to_bitmask(light_diagram);
+
+    let button_diagrams = line[(sb_end + 1)..cb_start].trim();
+    let button_diagrams = button_diagrams
+        .split(' ')
+        .map(|button| {
+            let indicies = button
+                .strip_prefix('(')
+                .and_then(|button| button.strip_suffix(')'))
+                .ok_or_else(|| error(button, "a button like '(0,2)'"))?;
+            let indicies = indicies
+                .split(',')
+                .map(|num| {
+                    num.parse::<u8>()
+                        .ok()
+                        .filter(|&index| (index as usize) < light_count)
+                        .ok_or_else(|| error(num, &format!("a light index below {light_count}")))
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(indicies_to_bitmask(indicies))
+        })
+        .collect::<Result<Vec<u16>, _>>()?;
+
+    let joltage_indicators = &line[(cb_start + 1)..line.len() - 1];
+    let joltage_indicators = joltage_indicators
+        .split(',')
+        .map(|num| num.parse::<u16>().map_err(|_| error(num, "a joltage")))
+        .collect::<Result<Vec<u16>, _>>()?;
+
+    if !can_light(light_diagram, &button_diagrams) {
+        return Err(error(
+            &line[1..sb_end],
+            "lights that some buttons can light",
+        ));
+    }
+
+    Ok((light_diagram, button_diagrams, joltage_indicators))
+}
+
+/// Whether pressing some set of the buttons lights exactly `target`, by
+/// reducing it against a basis of the buttons, each with its own highest
+/// light.
+fn can_light(target: u16, buttons: &[u16]) -> bool {
+    let reduce = |basis: &[u16], lights: u16| {
+        basis
+            .iter()
+            .fold(lights, |lights, &button| lights.min(lights ^ button))
+    };
+    let mut basis: Vec<u16> = Vec::new();
+    for &button in buttons {
+        let reduced = reduce(&basis, button);
+        if reduced != 0 {
+            basis.push(reduced);
+            basis.sort_unstable_by(|a, b| b.cmp(a));
+        }
+    }
+    reduce(&basis, target) == 0
+}
+
+fn indicies_to_bitmask(indicies: Vec<u8>) -> u16 {
+    let mut mask: u16 = 0;
+    for index in indicies {
+        mask |= 1 << index
+    }
+    mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day10/example.txt");
+
+    #[test]
+    fn test_min_presses_sum_example() {
+        let input = EXAMPLE;
+        let got = min_press_sum(&convert_input(input).unwrap());
+
+        assert_eq!(7, got);
+    }
+
+    #[test]
+    fn test_min_presses() {
+        let button_input = vec![0b1000, 0b1010, 0b100, 0b1100, 0b101, 0b11];
+        let got = min_presses(0b110, &button_input);
+
+        assert_eq!(Some(2), got);
+        assert_eq!(min_presses(0, &button_input), Some(0));
+        assert_eq!(min_presses(0b1, &[0b11, 0b10, 0b11]), Some(2));
+        assert_eq!(min_presses(0b100, &[0b11, 0b10]), None);
+    }
+
+    #[test]
+    fn test_convert_input_example() {
+        let input = EXAMPLE;
+        let want = vec![
+            (
+                0b110,
+                vec![0b1000, 0b1010, 0b100, 0b1100, 0b101, 0b11],
+                vec![3, 5, 4, 7],
+            ),
+            (
+                0b1000,
+                vec![0b11101, 0b1100, 0b10001, 0b111, 0b11110],
+                vec![7, 5, 12, 7, 2],
+            ),
+            (
+                0b101110,
+                vec![0b11111, 0b11001, 0b110111, 0b110],
+                vec![10, 11, 11, 5, 10, 5],
+            ),
+        ];
+
+        assert_eq!(Ok(want), convert_input(input));
+    }
+
+    #[test]
+    fn test_convert_input_errors() {
+        assert_eq!(
+            convert_input("[.##.] (3) (1,2) {3,5,4,7}\n[.#x.] (3) {3}"),
+            Err(ParseError::new(10, 2, 4, "x", "'#' or '.'"))
+        );
+        assert_eq!(
+            convert_input("[.##.] (3) (1,4) {3,5,4,7}"),
+            Err(ParseError::new(10, 1, 15, "4", "a light index below 4"))
+        );
+        assert_eq!(
+            convert_input("[.##.] (3) 1,3 {3,5,4,7}"),
+            Err(ParseError::new(10, 1, 12, "1,3", "a button like '(0,2)'"))
+        );
+        assert_eq!(
+            convert_input("[.##.] (3) (1,3) {3,5,,7}"),
+            Err(ParseError::new(10, 1, 23, "", "a joltage"))
+        );
+        assert_eq!(
+            convert_input("[.##. (3) {3}"),
+            Err(ParseError::new(10, 1, 1, "[.##. (3) {3}", "a ']'"))
+        );
+        assert_eq!(
+            convert_input("[.##.] (3) (1,3) {3,5,4,7}"),
+            Err(ParseError::new(
+                10,
+                1,
+                2,
+                ".##.",
+                "lights that some buttons can light"
+            ))
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use std::ops::RangeInclusive;
+
+use crate::{Solution, error::ParseError, interval::IntervalSet};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Ranges;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_ranges(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::Answer1 {
+        id_sum(input, invalid_id)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Self::Answer2> {
+        Some(id_sum(input, invalid_id_two))
+    }
+
+    fn naive_part_one(input: &str, _options: &[(String, String)]) -> Option<Self::Answer1> {
+        Some(id_sum_naive(input, |id| {
+            let (first, second) = id.split_at(id.len() / 2);
+            first == second
+        }))
+    }
+
+    fn naive_part_two(input: &str, _options: &[(String, String)]) -> Option<Self::Answer2> {
+        Some(id_sum_naive(input, |id| {
+            (1..id.len())
+                .any(|size| id.len() % size == 0 && id[..size].repeat(id.len() / size) == id)
+        }))
+    }
+}
+
+/// The IDs a list of ranges covers, in layers: an ID in `n` of the ranges
+/// is in the first `n` layers. Each layer is merged, yet summing over all
+/// of them still counts each range on its own, as the puzzle does.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ranges {
+    layers: Vec<IntervalSet<i64>>,
+}
+
+impl Ranges {
+    /// Adds `range` to the first layer, passing the part that overlaps it
+    /// down to the next.
+    pub fn insert(&mut self, range: RangeInclusive<i64>) {
+        let mut rest: IntervalSet<i64> = [range].into_iter().collect();
+        for layer in &mut self.layers {
+            if rest.is_empty() {
+                return;
+            }
+            let overlap = layer.intersection(&rest);
+            *layer = layer.union(&rest);
+            rest = overlap;
+        }
+        if !rest.is_empty() {
+            self.layers.push(rest);
+        }
+    }
+
+    pub fn layers(&self) -> &[IntervalSet<i64>] {
+        &self.layers
+    }
+}
+
+/// Sums the invalid IDs of each range on its own, so an ID in two
+/// overlapping ranges counts twice.
+pub fn id_sum(ranges: &Ranges, invalid_func: fn(i64) -> bool) -> i64 {
+    ranges
+        .layers()
+        .iter()
+        .flat_map(IntervalSet::ranges)
+        .map(|range| range.filter(|&id| invalid_func(id)).sum::<i64>())
+        .sum()
+}
+
+/// Sums every ID whose decimal text matches `invalid`.
+pub fn id_sum_naive(input: &str, invalid: fn(&str) -> bool) -> i64 {
+    let mut sum = 0;
+    for range in input.trim().split(',') {
+        let (start, end) = range.split_once('-').unwrap();
+        for id in start.parse::<i64>().unwrap()..=end.parse().unwrap() {
+            if invalid(&id.to_string()) {
+                sum += id;
+            }
+        }
+    }
+    sum
+}
+
+pub fn parse_ranges(ranges: &str) -> Result<Ranges, ParseError> {
+    let mut parsed = Ranges::default();
+    for (index, line) in ranges.trim_end().lines().enumerate() {
+        for range in line.split(',') {
+            let range = parse_range(line, range).map_err(|err| err.at_line(index + 1))?;
+            parsed.insert(range);
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn parse_range(line: &str, range: &str) -> Result<RangeInclusive<i64>, ParseError> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::in_line(Day2::DAY, line, range, "a range like '11-22'"))?;
+    let start: i64 = start
+        .parse()
+        .map_err(|_| ParseError::in_line(Day2::DAY, line, start, "a range start"))?;
+    let end: i64 = end
+        .parse()
+        .map_err(|_| ParseError::in_line(Day2::DAY, line, end, "a range end"))?;
+
+    Ok(start..=end)
+}
+
+pub fn invalid_id_two(id: i64) -> bool {
+    let str = id.to_string();
+    let digits = str.len();
+
+    (1..=(digits / 2)).any(|size| {
+        let first = &str.as_bytes()[..size];
+        str.as_bytes().chunks(size).all(|chunk| chunk == first)
+    })
+}
+
+pub fn invalid_id(id: i64) -> bool {
+    let digits = id.checked_ilog10().unwrap_or(0) + 1;
+    let val = 10_i64.pow(digits / 2);
+
+    id / val == id % val
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day2/example.txt");
+
+    #[test]
+    fn test_invalid_id_invalid_two_short() {
+        assert!(invalid_id_two(11), "expected invalid got valid");
+    }
+
+    #[test]
+    fn test_invalid_id_invalid_two_long() {
+        assert!(invalid_id_two(121212), "expected invalid got valid")
+    }
+
+    #[test]
+    fn test_invalid_id_valid_two_long() {
+        assert!(!invalid_id_two(12345), "expected invalid got valid")
+    }
+
+    #[test]
+    fn test_id_sum_simple() {
+        let id_ranges = "11-22";
+        assert_eq!(id_sum(&parse_ranges(id_ranges).unwrap(), invalid_id), 33)
+    }
+
+    #[test]
+    fn test_id_sum_multiple() {
+        let id_ranges = "11-22,33-44";
+        assert_eq!(id_sum(&parse_ranges(id_ranges).unwrap(), invalid_id), 110)
+    }
+
+    #[test]
+    fn test_id_sum_overlapping() {
+        // 22 is in both ranges, so it counts twice: 11 + 22 + 22 + 33.
+        let id_ranges = "11-22,20-33";
+        assert_eq!(id_sum(&parse_ranges(id_ranges).unwrap(), invalid_id), 88)
+    }
+
+    #[test]
+    fn test_ranges_layers() {
+        let ranges = parse_ranges("11-22,20-33,21-21,40-30").unwrap();
+        let layers: Vec<Vec<_>> = ranges
+            .layers()
+            .iter()
+            .map(|layer| layer.ranges().collect())
+            .collect();
+        assert_eq!(layers, [vec![11..=33], vec![20..=22], vec![21..=21]]);
+    }
+
+    #[test]
+    fn test_id_sum_example() {
+        let id_ranges = EXAMPLE;
+        assert_eq!(
+            id_sum(&parse_ranges(id_ranges).unwrap(), invalid_id),
+            1227775554
+        )
+    }
+
+    #[test]
+    fn test_id_sum_other_func_example() {
+        let id_ranges = EXAMPLE;
+        assert_eq!(
+            id_sum(&parse_ranges(id_ranges).unwrap(), invalid_id_two),
+            4174379265
+        )
+    }
+
+    #[test]
+    fn test_parse_ranges_errors() {
+        assert_eq!(
+            parse_ranges("11-22,95115"),
+            Err(ParseError::new(2, 1, 7, "95115", "a range like '11-22'"))
+        );
+        assert_eq!(
+            parse_ranges("11-22,\n95-1x5"),
+            Err(ParseError::new(2, 1, 7, "", "a range like '11-22'"))
+        );
+        assert_eq!(
+            parse_ranges("11-22\n95-1x5"),
+            Err(ParseError::new(2, 2, 4, "1x5", "a range end"))
+        );
+    }
+
+    #[test]
+    fn test_invalid_id_invalid_short() {
+        assert!(invalid_id(11))
+    }
+
+    #[test]
+    fn test_invalid_id_invalid_long() {
+        assert!(invalid_id(1234577712345777))
+    }
+
+    #[test]
+    fn test_invalid_id_valid() {
+        assert!(!invalid_id(123456))
+    }
+}
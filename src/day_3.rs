@@ -0,0 +1,225 @@
+use crate::{Solution, error::ParseError};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u64>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    /// Banks must hold enough batteries for part two to turn on.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                let bank = bank_digits(line).map_err(|err| err.at_line(index + 1))?;
+                if bank.len() < PART_TWO_DIGITS {
+                    let expected = format!("a bank of at least {PART_TWO_DIGITS} batteries");
+                    return Err(
+                        ParseError::in_line(Day3::DAY, line, line, expected).at_line(index + 1)
+                    );
+                }
+                Ok(bank)
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input) -> Self::Answer1 {
+        total_joltage(input, 2)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Self::Answer2> {
+        Some(total_joltage(input, PART_TWO_DIGITS))
+    }
+
+    fn naive_part_one(input: &str, _options: &[(String, String)]) -> Option<Self::Answer1> {
+        Some(total_joltage_naive(input, 2))
+    }
+
+    fn naive_part_two(input: &str, _options: &[(String, String)]) -> Option<Self::Answer2> {
+        Some(total_joltage_naive(input, PART_TWO_DIGITS))
+    }
+}
+
+/// How many batteries part two turns on in each bank.
+pub const PART_TWO_DIGITS: usize = 12;
+
+pub fn total_joltage(banks: &[Vec<u64>], digits: usize) -> u64 {
+    banks.iter().map(|bank| bank_joltage(bank, digits)).sum()
+}
+
+/// Tries every subsequence of `digits` batteries in each bank, remembering
+/// the best pick for each suffix and count so the search stays polynomial.
+pub fn total_joltage_naive(input: &str, digits: usize) -> u64 {
+    let mut total = 0;
+    for bank in input.lines() {
+        let bank: Vec<u64> = bank.bytes().map(|byte| (byte - b'0') as u64).collect();
+        let mut best = vec![vec![None; digits + 1]; bank.len() + 1];
+        best[bank.len()][0] = Some(0);
+        for index in (0..bank.len()).rev() {
+            best[index][0] = Some(0);
+            for count in 1..=digits {
+                let take = best[index + 1][count - 1]
+                    .map(|rest| bank[index] * 10_u64.pow(count as u32 - 1) + rest);
+                best[index][count] = take.max(best[index + 1][count]);
+            }
+        }
+        total += best[0][digits].unwrap();
+    }
+    total
+}
+
+pub fn bank_digits(bank: &str) -> Result<Vec<u64>, ParseError> {
+    bank.char_indices()
+        .map(|(index, char)| {
+            char.to_digit(10).map(|d| d as u64).ok_or_else(|| {
+                let part = &bank[index..index + char.len_utf8()];
+                ParseError::in_line(Day3::DAY, bank, part, "a digit")
+            })
+        })
+        .collect()
+}
+
+pub fn bank_joltage(digits: &[u64], digits_count: usize) -> u64 {
+    let last_index = digits.len() - digits_count;
+    bank_joltage_rec(digits, 0, last_index)
+}
+
+pub fn bank_joltage_rec(bank: &[u64], start: usize, end: usize) -> u64 {
+    assert!(start <= end);
+    if end >= bank.len() {
+        return 0;
+    }
+
+    let mut max_index = start;
+    let mut max_value = bank[max_index];
+    for (index, value) in bank[start..=end].iter().enumerate() {
+        let index = index + start;
+        if *value > max_value {
+            max_value = *value;
+            max_index = index;
+        }
+    }
+
+    let pow = bank.len() - end - 1;
+    max_value * 10_u64.pow(pow as u32) + bank_joltage_rec(bank, max_index + 1, end + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day3/example.txt");
+
+    #[test]
+    fn test_total_joltage_twelve_digits() {
+        let got = total_joltage(&Day3::parse(EXAMPLE).unwrap(), 12);
+        assert_eq!(got, 3121910778619);
+    }
+
+    #[test]
+    fn test_total_joltage_two_digits() {
+        let got = total_joltage(&Day3::parse(EXAMPLE).unwrap(), 2);
+        assert_eq!(got, 357);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Day3::parse("987654321111111\n8111111 1111119"),
+            Err(ParseError::new(3, 2, 8, " ", "a digit"))
+        );
+        assert_eq!(
+            Day3::parse("987654321111111\n12345678901"),
+            Err(ParseError::new(
+                3,
+                2,
+                1,
+                "12345678901",
+                "a bank of at least 12 batteries"
+            ))
+        );
+        assert!(Day3::parse("1\n2").is_err());
+    }
+
+    #[test]
+    fn test_bank_jolts_first_two() {
+        let digits: Vec<u64> = "987654321111111"
+            .chars()
+            .map(|char| char.to_digit(10).unwrap() as u64)
+            .collect();
+
+        let len = digits.len() - 2;
+        let got = bank_joltage_rec(&digits, 0, len);
+        assert_eq!(got, 98);
+    }
+
+    #[test]
+    fn test_bank_joltage_first_two() {
+        let got = bank_joltage(&bank_digits("987654321111111").unwrap(), 2);
+        assert_eq!(got, 98);
+    }
+
+    #[test]
+    fn test_bank_joltage_first_twelve() {
+        let got = bank_joltage(&bank_digits("987654321111111").unwrap(), 12);
+        assert_eq!(got, 987654321111);
+    }
+
+    #[test]
+    fn test_bank_joltage_first_last() {
+        let got = bank_joltage(&bank_digits("811111111111119").unwrap(), 2);
+        assert_eq!(got, 89);
+    }
+
+    #[test]
+    fn test_bank_joltage_first_last_twelve() {
+        let got = bank_joltage(&bank_digits("811111111111119").unwrap(), 12);
+        assert_eq!(got, 811111111119);
+    }
+
+    #[test]
+    fn test_bank_joltage_last_two() {
+        let got = bank_joltage(&bank_digits("234234234234278").unwrap(), 2);
+        assert_eq!(got, 78);
+    }
+
+    #[test]
+    fn test_bank_joltage_last_twelve() {
+        let got = bank_joltage(&bank_digits("234234234234278").unwrap(), 12);
+        assert_eq!(got, 434234234278);
+    }
+
+    #[test]
+    fn test_bank_joltage_middle_two() {
+        let got = bank_joltage(&bank_digits("818181911112111").unwrap(), 2);
+        assert_eq!(got, 92);
+    }
+
+    #[test]
+    fn test_bank_joltage_middle_twelve() {
+        let got = bank_joltage(&bank_digits("818181911112111").unwrap(), 12);
+        assert_eq!(got, 888911112111);
+    }
+
+    #[test]
+    fn test_bank_joltage_dupes() {
+        let got = bank_joltage(&bank_digits("99818181911112111").unwrap(), 2);
+        assert_eq!(got, 99);
+    }
+
+    #[test]
+    fn test_bank_joltage_two_dupes_then_biggest() {
+        let got = bank_joltage(
+            &bank_digits(
+                "3432122222342332232122322311242221234222223223223222124222222322212123122323222222222131451231223211",
+            )
+            .unwrap(),
+            2,
+        );
+        assert_eq!(got, 53);
+    }
+}
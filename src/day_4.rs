@@ -0,0 +1,178 @@
+use crate::{
+    Solution,
+    error::ParseError,
+    grid::{Grid, Pos},
+};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<Option<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        build_access_counts(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::Answer1 {
+        num_forklift_accessible(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Self::Answer2> {
+        Some(num_forklift_accessible_repeat(input))
+    }
+
+    fn naive_part_one(input: &str, _options: &[(String, String)]) -> Option<Self::Answer1> {
+        Some(removal_rounds(input).first().copied().unwrap_or(0))
+    }
+
+    fn naive_part_two(input: &str, _options: &[(String, String)]) -> Option<Self::Answer2> {
+        Some(removal_rounds(input).iter().sum())
+    }
+}
+
+/// Rescans the whole grid each round, removing every roll with fewer than
+/// four neighbouring rolls. Returns how many were removed in each round.
+pub fn removal_rounds(input: &str) -> Vec<i32> {
+    let mut grid: Vec<Vec<bool>> = input
+        .lines()
+        .map(|line| line.chars().map(|char| char == '@').collect())
+        .collect();
+    let mut rounds = Vec::new();
+    loop {
+        let mut removable = Vec::new();
+        for (y, row) in grid.iter().enumerate() {
+            for (x, &roll) in row.iter().enumerate() {
+                let neighbours = (y.saturating_sub(1)..=y + 1)
+                    .flat_map(|ny| (x.saturating_sub(1)..=x + 1).map(move |nx| (nx, ny)))
+                    .filter(|&(nx, ny)| (nx, ny) != (x, y))
+                    .filter(|&(nx, ny)| grid.get(ny).and_then(|row| row.get(nx)) == Some(&true))
+                    .count();
+                if roll && neighbours < 4 {
+                    removable.push((x, y));
+                }
+            }
+        }
+        if removable.is_empty() {
+            return rounds;
+        }
+        rounds.push(removable.len() as i32);
+        for (x, y) in removable {
+            grid[y][x] = false;
+        }
+    }
+}
+
+pub fn num_forklift_accessible_repeat(counts: &Grid<Option<i32>>) -> i32 {
+    let mut counts = counts.clone();
+    let mut num_removed = 0;
+
+    loop {
+        let removed_count = remove_packages(&mut counts);
+        num_removed += removed_count;
+
+        if removed_count == 0 {
+            break;
+        }
+    }
+
+    num_removed
+}
+
+pub fn num_forklift_accessible(counts: &Grid<Option<i32>>) -> i32 {
+    remove_packages(&mut counts.clone())
+}
+
+/// Removes every roll with fewer than four neighbouring rolls, returning how
+/// many were removed.
+fn remove_packages(counts: &mut Grid<Option<i32>>) -> i32 {
+    let values_to_remove: Vec<Pos> = counts
+        .iter()
+        .filter(|(_, value)| value.is_some_and(|count| count < 4))
+        .map(|(pos, _)| pos)
+        .collect();
+
+    let num_removed = values_to_remove.len() as i32;
+
+    for pos in values_to_remove {
+        counts[pos] = None;
+
+        for adjacent in counts.neighbours8(pos) {
+            if let Some(count) = &mut counts[adjacent] {
+                *count -= 1;
+            }
+        }
+    }
+
+    num_removed
+}
+
+/// Counts the neighbouring rolls of every roll. Cells without a roll are
+/// `None`.
+pub fn build_access_counts(grid: &str) -> Result<Grid<Option<i32>>, ParseError> {
+    let rolls = Grid::parse(Day4::DAY, grid, "'@' or '.'", |char| match char {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    let mut access = rolls.map(|&roll| roll.then_some(0));
+    for (pos, &roll) in rolls.iter() {
+        if roll {
+            for adjacent in rolls.neighbours8(pos) {
+                if let Some(count) = &mut access[adjacent] {
+                    *count += 1;
+                }
+            }
+        }
+    }
+
+    Ok(access)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day4/example.txt");
+
+    #[test]
+    fn test_forklift_access() {
+        let input = "@@@
+@@@
+@@@";
+        let digits = |char: char| char.to_digit(10).map(|count| Some(count as i32));
+        let want = Grid::parse(4, "353\n585\n353", "a count", digits).unwrap();
+        let got = build_access_counts(input);
+        assert_eq!(got, Ok(want));
+    }
+
+    #[test]
+    fn test_build_access_counts_errors() {
+        assert_eq!(
+            build_access_counts("@.@\n@x@"),
+            Err(ParseError::new(4, 2, 2, "x", "'@' or '.'"))
+        );
+        assert_eq!(
+            build_access_counts("@.@\n@@"),
+            Err(ParseError::new(4, 2, 1, "@@", "a row of width 3"))
+        );
+    }
+
+    #[test]
+    fn test_num_forklift_accessible() {
+        let input = EXAMPLE;
+        let got = num_forklift_accessible(&build_access_counts(input).unwrap());
+        assert_eq!(got, 13);
+    }
+
+    #[test]
+    fn test_num_forklift_accessible_repeat() {
+        let input = EXAMPLE;
+        let got = num_forklift_accessible_repeat(&build_access_counts(input).unwrap());
+        assert_eq!(got, 43);
+    }
+}
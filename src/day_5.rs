@@ -0,0 +1,228 @@
+use crate::{Solution, error::ParseError, interval::IntervalSet};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Inventory;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_inventory(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::Answer1 {
+        count_fresh(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Self::Answer2> {
+        Some(count_possible_fresh(input))
+    }
+
+    fn naive_part_one(input: &str, _options: &[(String, String)]) -> Option<Self::Answer1> {
+        let (ranges, ids) = naive_inventory(input);
+        let fresh = ids
+            .iter()
+            .filter(|&&id| ranges.iter().any(|&(start, end)| start <= id && id <= end));
+        Some(fresh.count() as u64)
+    }
+
+    fn naive_part_two(input: &str, _options: &[(String, String)]) -> Option<Self::Answer2> {
+        let (ranges, _) = naive_inventory(input);
+        // Every range starts or stops at one of these points, so each gap
+        // between two of them is either entirely fresh or entirely not.
+        let mut points: Vec<u64> = ranges
+            .iter()
+            .flat_map(|&(start, end)| [start, end + 1])
+            .collect();
+        points.sort_unstable();
+        points.dedup();
+        let fresh = points.windows(2).filter(|pair| {
+            ranges
+                .iter()
+                .any(|&(start, end)| start <= pair[0] && pair[0] <= end)
+        });
+        Some(fresh.map(|pair| pair[1] - pair[0]).sum())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Inventory {
+    pub fresh: IntervalSet<u64>,
+    pub ids: Vec<u64>,
+}
+
+/// Reads the ranges as written, without merging them, and the IDs.
+fn naive_inventory(input: &str) -> (Vec<(u64, u64)>, Vec<u64>) {
+    let (ranges, ids) = input.split_once("\n\n").unwrap();
+    let ranges = ranges
+        .lines()
+        .map(|range| {
+            let (start, end) = range.split_once('-').unwrap();
+            (start.parse().unwrap(), end.parse().unwrap())
+        })
+        .collect();
+    let ids = ids.lines().map(|id| id.parse().unwrap()).collect();
+    (ranges, ids)
+}
+
+pub fn count_possible_fresh(inventory: &Inventory) -> u64 {
+    inventory.fresh.len()
+}
+
+pub fn count_fresh(inventory: &Inventory) -> u64 {
+    inventory
+        .ids
+        .iter()
+        .filter(|&&id| inventory.fresh.contains(id))
+        .count() as u64
+}
+
+pub fn parse_inventory(input: &str) -> Result<Inventory, ParseError> {
+    let Some((input_ranges, ids)) = input.split_once("\n\n") else {
+        let line = input.lines().count() + 1;
+        let expected = "a blank line between ranges and ids";
+        return Err(ParseError::new(Day5::DAY, line, 1, "", expected));
+    };
+    let fresh = fresh_ranges(input_ranges)?;
+    let first_id_line = input_ranges.lines().count() + 2;
+    let ids = ids
+        .lines()
+        .enumerate()
+        .map(|(index, id)| {
+            id.parse::<u64>().map_err(|_| {
+                ParseError::new(Day5::DAY, first_id_line + index, 1, id, "an ingredient id")
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Inventory { fresh, ids })
+}
+
+pub fn fresh_ranges(ids: &str) -> Result<IntervalSet<u64>, ParseError> {
+    let ranges: Vec<(u64, u64)> = ids
+        .lines()
+        .enumerate()
+        .map(|(index, range)| parse_range(range).map_err(|err| err.at_line(index + 1)))
+        .collect::<Result<_, _>>()?;
+
+    if ranges.is_empty() {
+        return Err(ParseError::new(
+            Day5::DAY,
+            1,
+            1,
+            ids,
+            "at least one fresh range",
+        ));
+    }
+
+    Ok(ranges.into_iter().map(|(start, end)| start..=end).collect())
+}
+
+fn parse_range(range: &str) -> Result<(u64, u64), ParseError> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::in_line(Day5::DAY, range, range, "a range like '3-5'"))?;
+    let start = start
+        .parse()
+        .map_err(|_| ParseError::in_line(Day5::DAY, range, start, "a range start"))?;
+    let end = end
+        .parse()
+        .map_err(|_| ParseError::in_line(Day5::DAY, range, end, "a range end"))?;
+
+    Ok((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day5/example.txt");
+
+    #[test]
+    fn test_fresh_ranges_overlapping() {
+        let input = "0-2
+1-4
+6-8";
+        let want = Vec::from([0..=4, 6..=8]);
+
+        let got: Vec<_> = fresh_ranges(input).unwrap().ranges().collect();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn test_fresh_ranges_consuming() {
+        let input = "0-9
+1-4
+6-8";
+        let want = Vec::from([0..=9]);
+
+        let got: Vec<_> = fresh_ranges(input).unwrap().ranges().collect();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn test_fresh_ranges_short() {
+        let input = "0-4
+6-8";
+        let want = Vec::from([0..=4, 6..=8]);
+
+        let got: Vec<_> = fresh_ranges(input).unwrap().ranges().collect();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn test_parse_inventory_errors() {
+        assert_eq!(
+            parse_inventory("3-5\n10-1x\n\n1"),
+            Err(ParseError::new(5, 2, 4, "1x", "a range end"))
+        );
+        assert_eq!(
+            parse_inventory("3-5\n10-14\n\n1\n5a"),
+            Err(ParseError::new(5, 5, 1, "5a", "an ingredient id"))
+        );
+        assert_eq!(
+            parse_inventory("3-5\n10-14"),
+            Err(ParseError::new(
+                5,
+                3,
+                1,
+                "",
+                "a blank line between ranges and ids"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_count_fresh_short() {
+        let ids = "0-4
+6-8
+
+1
+2
+3
+4
+5
+6
+7
+8";
+
+        assert_eq!(count_fresh(&parse_inventory(ids).unwrap()), 7);
+    }
+
+    #[test]
+    fn test_count_fresh_example() {
+        let ids = EXAMPLE;
+
+        assert_eq!(count_fresh(&parse_inventory(ids).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_count_possible_fresh_example() {
+        let ids = EXAMPLE;
+
+        assert_eq!(count_possible_fresh(&parse_inventory(ids).unwrap()), 14);
+    }
+}
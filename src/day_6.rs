@@ -0,0 +1,355 @@
+use crate::{Solution, error::ParseError};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        check_worksheet(input)?;
+        Ok(Worksheet {
+            human: convert_input_human(input),
+            ceph: covert_input_ceph(input),
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Self::Answer1 {
+        do_math(&input.human)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Self::Answer2> {
+        Some(do_math(&input.ceph))
+    }
+
+    fn naive_part_one(input: &str, _options: &[(String, String)]) -> Option<Self::Answer1> {
+        let lines: Vec<Vec<&str>> = input
+            .lines()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        let (operators, numbers) = lines.split_last().unwrap();
+        let total = operators.iter().enumerate().map(|(problem, operator)| {
+            let numbers = numbers
+                .iter()
+                .map(|row| row[problem].parse::<u64>().unwrap());
+            apply(operator, numbers)
+        });
+        Some(total.sum())
+    }
+
+    fn naive_part_two(input: &str, _options: &[(String, String)]) -> Option<Self::Answer2> {
+        let grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+        let (operators, numbers) = grid.split_last().unwrap();
+
+        // Read columns right to left; each problem ends at its operator.
+        let mut total = 0;
+        let mut problem = Vec::new();
+        for column in (0..operators.len()).rev() {
+            let digits: String = numbers
+                .iter()
+                .map(|row| row[column])
+                .filter(char::is_ascii_digit)
+                .collect();
+            if !digits.is_empty() {
+                problem.push(digits.parse::<u64>().unwrap());
+            }
+            if operators[column] != ' ' {
+                total += apply(&operators[column].to_string(), problem.drain(..));
+            }
+        }
+        Some(total)
+    }
+}
+
+fn apply(operator: &str, numbers: impl Iterator<Item = u64>) -> u64 {
+    match operator {
+        "+" => numbers.sum(),
+        "*" => numbers.product(),
+        _ => panic!("invalid operator: {operator}"),
+    }
+}
+
+/// The same worksheet read left-to-right by rows and right-to-left by columns.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Worksheet {
+    pub human: Vec<Vec<String>>,
+    pub ceph: Vec<Vec<String>>,
+}
+
+/// Both readings index the worksheet by column, so every row must have the
+/// same width and the last row must hold one operator per problem.
+pub fn check_worksheet(input: &str) -> Result<(), ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let Some((operators, numbers)) = lines
+        .split_last()
+        .filter(|(_, numbers)| !numbers.is_empty())
+    else {
+        let line = lines.len() + 1;
+        return Err(ParseError::new(
+            Day6::DAY,
+            line,
+            1,
+            "",
+            "rows of numbers then operators",
+        ));
+    };
+
+    let width = lines[0].chars().count();
+    let problem_count = operators.split_whitespace().count();
+    for (index, line) in lines.iter().enumerate() {
+        if line.chars().count() != width {
+            let expected = format!("a row of width {width}");
+            return Err(ParseError::new(Day6::DAY, index + 1, 1, *line, expected));
+        }
+    }
+
+    for (index, line) in numbers.iter().enumerate() {
+        if let Some((column, char)) = line
+            .chars()
+            .enumerate()
+            .find(|(_, char)| !char.is_ascii_digit() && *char != ' ')
+        {
+            let found = char.to_string();
+            return Err(ParseError::new(
+                Day6::DAY,
+                index + 1,
+                column + 1,
+                found,
+                "a digit",
+            ));
+        }
+        if line.split_whitespace().count() != problem_count {
+            let expected = format!("{problem_count} numbers");
+            return Err(ParseError::new(Day6::DAY, index + 1, 1, *line, expected));
+        }
+    }
+
+    let line = lines.len();
+    if let Some((column, char)) = operators
+        .chars()
+        .enumerate()
+        .find(|&(column, char)| !matches!(char, '+' | '*' | ' ') || (column == 0 && char == ' '))
+    {
+        let found = char.to_string();
+        return Err(ParseError::new(
+            Day6::DAY,
+            line,
+            column + 1,
+            found,
+            "'+' or '*'",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn do_math(problems: &[Vec<String>]) -> u64 {
+    problems
+        .iter()
+        .map(|problem| {
+            let action = match problem.last().unwrap().as_str() {
+                "+" => |lhs: u64, rhs: u64| lhs + rhs,
+                "*" => |lhs: u64, rhs: u64| lhs * rhs,
+                input => panic!("invalid input: {} in problem: {problem:?}", input),
+            };
+            let first_val = problem[0]
+                .parse::<u64>()
+                .unwrap_or_else(|err| panic!("failed to parse {}: {err}", problem[0]));
+
+            problem[1..problem.len() - 1]
+                .iter()
+                .fold(first_val, |accum, val| {
+                    let v = val
+                        .parse::<u64>()
+                        .unwrap_or_else(|err| panic!("failed to parse {val}: {err}"));
+                    action(accum, v)
+                })
+        })
+        .sum()
+}
+
+pub fn convert_input_human(input: &str) -> Vec<Vec<String>> {
+    let problem_count = input.lines().next().unwrap().split_whitespace().count();
+    let mut result: Vec<Vec<String>> = Vec::with_capacity(problem_count);
+
+    let line_count = input.lines().count();
+    for line in input.lines() {
+        for (problem_index, val) in line.split_whitespace().enumerate() {
+            if problem_index >= result.len() {
+                result.push(Vec::with_capacity(line_count));
+            }
+            result[problem_index].push(val.to_string());
+        }
+    }
+    result
+}
+
+fn sizes(inputs: &str) -> Vec<(&str, usize)> {
+    let syms: Vec<&str> = inputs.split_whitespace().collect();
+    let lens: Vec<usize> = inputs
+        .split(['*', '+'])
+        .enumerate()
+        .map(|(num, spaces)| {
+            if num == syms.len() {
+                spaces.len() + 1
+            } else {
+                spaces.len()
+            }
+        })
+        .collect();
+
+    lens.into_iter()
+        .skip(1)
+        .zip(syms)
+        .map(|(len, sym)| (sym, len))
+        .collect()
+}
+
+pub fn covert_input_ceph(input: &str) -> Vec<Vec<String>> {
+    let line_len = input.split_inclusive('\n').next().unwrap().len();
+    let line_count = input.lines().count();
+    let last_line = input
+        .trim_end_matches('\n')
+        .split('\n')
+        .next_back()
+        .unwrap();
+    let problem_sizes = sizes(last_line);
+
+    problem_sizes
+        .iter()
+        .enumerate()
+        .map(|(problem_num, (action, problem_size))| {
+            let mut strings: Vec<String> = Vec::with_capacity(*problem_size);
+            for value_number in (0..*problem_size).rev() {
+                let mut number_buffer = String::with_capacity(*problem_size);
+                let offset: usize = problem_sizes[0..problem_num]
+                    .iter()
+                    .map(|(_, len)| *len + 1)
+                    .sum();
+                for index in 0..(line_count - 1) {
+                    let digit_index = offset + value_number + index * line_len;
+                    let digit_char = input
+                        .chars()
+                        .nth(digit_index)
+                        .expect("could not find char at index");
+                    if digit_char != ' ' {
+                        number_buffer.push(digit_char);
+                    }
+                }
+                // A column with no digits in any row holds no number.
+                if !number_buffer.is_empty() {
+                    strings.push(number_buffer);
+                }
+            }
+            strings.push(action.to_string());
+
+            strings
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day6/example.txt");
+
+    #[test]
+    fn test_problem_sizes() {
+        let input = "* +   *   +  ";
+        let want = [("*", 1), ("+", 3), ("*", 3), ("+", 3)];
+
+        assert_eq!(sizes(input), want);
+    }
+
+    #[test]
+    fn test_covert_input() {
+        let input = EXAMPLE;
+
+        let want = [
+            ["123", "45", "6", "*"],
+            ["328", "64", "98", "+"],
+            ["51", "387", "215", "*"],
+            ["64", "23", "314", "+"],
+        ];
+
+        assert_eq!(convert_input_human(input), want)
+    }
+
+    #[test]
+    fn test_covert_input_ceph() {
+        let input = EXAMPLE;
+
+        let want = [
+            ["356", "24", "1", "*"],
+            ["8", "248", "369", "+"],
+            ["175", "581", "32", "*"],
+            ["4", "431", "623", "+"],
+        ];
+
+        assert_eq!(covert_input_ceph(input), want)
+    }
+
+    #[test]
+    fn test_check_worksheet_errors() {
+        assert_eq!(
+            check_worksheet("12 3\n4x 5\n*  +"),
+            Err(ParseError::new(6, 2, 2, "x", "a digit"))
+        );
+        assert_eq!(
+            check_worksheet("12 3\n4 5\n*  +"),
+            Err(ParseError::new(6, 2, 1, "4 5", "a row of width 4"))
+        );
+        assert_eq!(
+            check_worksheet("12 3\n4  5\n* -+"),
+            Err(ParseError::new(6, 3, 3, "-", "'+' or '*'"))
+        );
+        assert_eq!(
+            check_worksheet("12 3\n45  \n*  +"),
+            Err(ParseError::new(6, 2, 1, "45  ", "2 numbers"))
+        );
+        assert_eq!(
+            check_worksheet("*  +"),
+            Err(ParseError::new(
+                6,
+                2,
+                1,
+                "",
+                "rows of numbers then operators"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_blank_digit_column() {
+        let input = "1  3\n4  5\n*  +";
+        let worksheet = Day6::parse(input).unwrap();
+
+        assert_eq!(worksheet.ceph, [["14", "*"], ["35", "+"]]);
+        assert_eq!(Day6::part_two(&worksheet), Some(49));
+        assert_eq!(Day6::naive_part_two(input, &[]), Some(49));
+
+        // The blank column can be the operator's own.
+        let input = " 1 3\n 4 5\n*  +";
+        let worksheet = Day6::parse(input).unwrap();
+        assert_eq!(Day6::part_two(&worksheet), Some(49));
+        assert_eq!(Day6::naive_part_two(input, &[]), Some(49));
+    }
+
+    #[test]
+    fn test_do_math_example_human() {
+        let input = EXAMPLE;
+
+        assert_eq!(do_math(&convert_input_human(input)), 4277556)
+    }
+
+    #[test]
+    fn test_do_math_example_ceph() {
+        let input = EXAMPLE;
+
+        assert_eq!(do_math(&covert_input_ceph(input)), 3263827)
+    }
+}
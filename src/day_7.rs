@@ -0,0 +1,233 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{Solution, error::ParseError, grid::Grid};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Manifold;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_manifold(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::Answer1 {
+        count_splits(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Self::Answer2> {
+        Some(count_timelines(input))
+    }
+
+    fn naive_part_one(input: &str, _options: &[(String, String)]) -> Option<Self::Answer1> {
+        let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let mut beams = BTreeSet::from([rows[0].iter().position(|&b| b == b'S').unwrap()]);
+        let mut splits = 0;
+        for row in &rows[1..] {
+            let mut next = BTreeSet::new();
+            for beam in beams {
+                match row.get(beam) {
+                    Some(b'^') => {
+                        splits += 1;
+                        next.extend(beam.checked_sub(1));
+                        next.extend(Some(beam + 1).filter(|&right| right < row.len()));
+                    }
+                    Some(_) => {
+                        next.insert(beam);
+                    }
+                    None => {}
+                }
+            }
+            beams = next;
+        }
+        Some(splits)
+    }
+
+    fn naive_part_two(input: &str, _options: &[(String, String)]) -> Option<Self::Answer2> {
+        let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let start = rows[0].iter().position(|&b| b == b'S').unwrap();
+        Some(timelines_from(&rows, 1, start, &mut HashMap::new()))
+    }
+}
+
+/// Follows one beam down from `row`, counting every way it can reach the
+/// bottom. Beams that leave the sides of the manifold are lost.
+fn timelines_from(
+    rows: &[&[u8]],
+    row: usize,
+    column: usize,
+    seen: &mut HashMap<(usize, usize), usize>,
+) -> usize {
+    if row == rows.len() {
+        return 1;
+    }
+    if let Some(&count) = seen.get(&(row, column)) {
+        return count;
+    }
+    let count = match rows[row].get(column) {
+        Some(b'^') => {
+            let left = column.checked_sub(1);
+            let right = Some(column + 1).filter(|&right| right < rows[row].len());
+            [left, right]
+                .into_iter()
+                .flatten()
+                .map(|column| timelines_from(rows, row + 1, column, seen))
+                .sum()
+        }
+        Some(_) => timelines_from(rows, row + 1, column, seen),
+        None => 0,
+    };
+    seen.insert((row, column), count);
+    count
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Manifold {
+    pub start: usize,
+    /// The rows below the start, with `true` where there is a splitter.
+    pub splitters: Grid<bool>,
+}
+
+pub fn parse_manifold(input: &str) -> Result<Manifold, ParseError> {
+    let start = start_index(input)?;
+    let rest = input.split_once('\n').map_or("", |(_, rest)| rest);
+    let splitters = Grid::parse(Day7::DAY, rest, "'.' or '^'", |char| match char {
+        '.' => Some(false),
+        '^' => Some(true),
+        _ => None,
+    })
+    .map_err(|err| {
+        let line = err.line + 1;
+        err.at_line(line)
+    })?;
+
+    Ok(Manifold { start, splitters })
+}
+
+pub fn count_timelines(manifold: &Manifold) -> usize {
+    let lasers = HashMap::from([(manifold.start, 1)]);
+    manifold
+        .splitters
+        .rows()
+        .fold(lasers, |map, row| split_lasers(map, row).1)
+        .values()
+        .sum()
+}
+
+fn start_index(input: &str) -> Result<usize, ParseError> {
+    let first_line = input.lines().next().unwrap_or("");
+    first_line
+        .find('S')
+        .ok_or_else(|| ParseError::new(Day7::DAY, 1, 1, first_line, "an 'S' in the first row"))
+}
+
+pub fn count_splits(manifold: &Manifold) -> usize {
+    let lasers = HashMap::from([(manifold.start, 1)]);
+    manifold
+        .splitters
+        .rows()
+        .fold((0, lasers), |(split_count, map), row| {
+            let (count, new_map) = split_lasers(map, row);
+            (split_count + count, new_map)
+        })
+        .0
+}
+
+pub fn split_lasers(lasers: HashMap<usize, usize>, row: &[bool]) -> (usize, HashMap<usize, usize>) {
+    lasers.into_iter().fold(
+        (0, HashMap::new()),
+        |(mut split_count, mut laser_pos_set), (laser_pos, timeline_count)| {
+            match row.get(laser_pos) {
+                Some(false) => {
+                    *laser_pos_set.entry(laser_pos).or_insert(0) += timeline_count;
+                }
+                Some(true) => {
+                    split_count += 1;
+                    // Beams split off the sides of the manifold are lost.
+                    let left = laser_pos.checked_sub(1);
+                    let right = Some(laser_pos + 1).filter(|&right| right < row.len());
+                    for pos in [left, right].into_iter().flatten() {
+                        *laser_pos_set.entry(pos).or_insert(0) += timeline_count;
+                    }
+                }
+                None => {}
+            }
+            (split_count, laser_pos_set)
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/day7/example.txt");
+
+    #[test]
+    fn test_split_lasers_basic() {
+        let lasers = HashMap::from([(1, 1)]);
+        let input = [false, true, false];
+
+        let want_map = HashMap::from([(0, 1), (2, 1)]);
+
+        let (got_count, got_set) = split_lasers(lasers, &input);
+
+        assert_eq!(got_set, want_map);
+        assert_eq!(got_count, 1);
+    }
+
+    #[test]
+    fn test_split_lasers_overlaps() {
+        let lasers = HashMap::from([(0, 1), (1, 2)]);
+        let input = [false, true, false];
+
+        let want_map = HashMap::from([(0, 3), (2, 2)]);
+
+        let (got_count, got_set) = split_lasers(lasers, &input);
+
+        assert_eq!(got_set, want_map);
+        assert_eq!(got_count, 1);
+    }
+
+    #[test]
+    fn test_splitters_at_edges() {
+        // The beam split off the side is lost, leaving one or none.
+        for (input, timelines) in [("S..\n^..", 1), ("..S\n..^", 1), ("S\n^", 0)] {
+            let manifold = Day7::parse(input).unwrap();
+            assert_eq!(Day7::part_one(&manifold), 1);
+            assert_eq!(Day7::part_two(&manifold), Some(timelines));
+            assert_eq!(Day7::naive_part_one(input, &[]), Some(1));
+            assert_eq!(Day7::naive_part_two(input, &[]), Some(timelines));
+        }
+    }
+
+    #[test]
+    fn test_parse_manifold_errors() {
+        assert_eq!(
+            parse_manifold("...\n.^."),
+            Err(ParseError::new(7, 1, 1, "...", "an 'S' in the first row"))
+        );
+        assert_eq!(
+            parse_manifold(".S.\n...\n.v."),
+            Err(ParseError::new(7, 3, 2, "v", "'.' or '^'"))
+        );
+    }
+
+    #[test]
+    fn test_count_splits() {
+        let input = EXAMPLE;
+
+        assert_eq!(count_splits(&parse_manifold(input).unwrap()), 21);
+    }
+
+    #[test]
+    fn test_count_timelines() {
+        let input = EXAMPLE;
+
+        assert_eq!(count_timelines(&parse_manifold(input).unwrap()), 40);
+    }
+}
@@ -0,0 +1,843 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+use crate::{
+    Solution,
+    error::ParseError,
+    point::{Coordinate, Metric, P3},
+    spatial::{pair_order, pairs_after, pairs_by_distance},
+    union_find::UnionFind,
+};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Playground;
+    type Answer1 = usize;
+    type Answer2 = Product;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Playground {
+            boxes: boxes(input)?,
+            connections: DEFAULT_CONNECTIONS,
+            top: DEFAULT_TOP,
+            metric: Metric::default(),
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Self::Answer1 {
+        let Playground {
+            connections,
+            top,
+            metric,
+            ..
+        } = *input;
+        match &input.boxes {
+            Boxes::Integers(boxes) => top_circuits(boxes, metric, connections, top),
+            Boxes::Floats(boxes) => top_circuits(boxes, metric, connections, top),
+        }
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Self::Answer2> {
+        match &input.boxes {
+            Boxes::Integers(boxes) => last_two_circuits(boxes, input.metric).map(Product::Integer),
+            Boxes::Floats(boxes) => last_two_circuits(boxes, input.metric).map(Product::Float),
+        }
+    }
+
+    fn naive_part_one(input: &str, options: &[(String, String)]) -> Option<Self::Answer1> {
+        let Playground {
+            boxes,
+            connections,
+            top,
+            metric,
+        } = configured(input, options);
+        Some(match boxes {
+            Boxes::Integers(boxes) => naive_top(&boxes, metric, connections, top),
+            Boxes::Floats(boxes) => naive_top(&boxes, metric, connections, top),
+        })
+    }
+
+    fn naive_part_two(input: &str, options: &[(String, String)]) -> Option<Self::Answer2> {
+        let Playground { boxes, metric, .. } = configured(input, options);
+        match boxes {
+            Boxes::Integers(boxes) => naive_last_two(&boxes, metric).map(Product::Integer),
+            Boxes::Floats(boxes) => naive_last_two(&boxes, metric).map(Product::Float),
+        }
+    }
+
+    fn configure(input: &mut Self::Input, name: &str, value: &str) -> Result<(), String> {
+        if name == "metric" {
+            input.metric = value.parse()?;
+            return Ok(());
+        }
+        let slot = match name {
+            "connections" => &mut input.connections,
+            "top" => &mut input.top,
+            _ => return Err(format!("no option {name:?}")),
+        };
+        *slot = value
+            .parse()
+            .map_err(|_| format!("{name} must be a number, got: {value:?}"))?;
+        Ok(())
+    }
+}
+
+/// Parses a well-formed input and sets `options` on it, for the naive
+/// references.
+fn configured(input: &str, options: &[(String, String)]) -> Playground {
+    let mut playground = Day8::parse(input).unwrap();
+    for (name, value) in options {
+        Day8::configure(&mut playground, name, value).unwrap();
+    }
+    playground
+}
+
+/// How many connections part one makes, as the puzzle asks.
+pub const DEFAULT_CONNECTIONS: usize = 1000;
+/// How many of the largest circuits part one multiplies.
+pub const DEFAULT_TOP: usize = 3;
+
+/// The parsed boxes, along with the parameters the parts are asked with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Playground {
+    pub boxes: Boxes,
+    pub connections: usize,
+    pub top: usize,
+    pub metric: Metric,
+}
+
+/// Box coordinates as parsed: integers when every coordinate is one, so
+/// that answers stay exact, and floats otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Boxes {
+    Integers(Vec<P3<i64>>),
+    Floats(Vec<P3<f64>>),
+}
+
+/// A product of coordinates, exact when they are integers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Product {
+    Integer(i64),
+    Float(f64),
+}
+
+impl Display for Product {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Product::Integer(product) => write!(f, "{product}"),
+            Product::Float(product) => write!(f, "{product}"),
+        }
+    }
+}
+
+/// Every pair of boxes by index, in the order of their connections.
+fn all_pairs<T: Coordinate>(boxes: &[P3<T>], metric: Metric) -> Vec<(T, usize, usize)> {
+    let mut pairs = Vec::new();
+    for first in 0..boxes.len() {
+        for second in first + 1..boxes.len() {
+            pairs.push((
+                metric.distance(&boxes[first], &boxes[second]),
+                first,
+                second,
+            ));
+        }
+    }
+    pairs.sort_by(|&(_, a, b), &(_, c, d)| pair_order(boxes, metric, (a, b), (c, d)));
+    pairs
+}
+
+/// Moves every box in the second box's circuit into the first one's.
+fn relabel(circuit: &mut [usize], first: usize, second: usize) {
+    let (keep, replace) = (circuit[first], circuit[second]);
+    for label in circuit.iter_mut().filter(|label| **label == replace) {
+        *label = keep;
+    }
+}
+
+fn naive_top<T: Coordinate>(
+    boxes: &[P3<T>],
+    metric: Metric,
+    connections: usize,
+    top: usize,
+) -> usize {
+    let mut circuit: Vec<usize> = (0..boxes.len()).collect();
+    for &(_, first, second) in all_pairs(boxes, metric).iter().take(connections) {
+        relabel(&mut circuit, first, second);
+    }
+
+    let mut sizes = vec![0; boxes.len()];
+    for &label in &circuit {
+        sizes[label] += 1;
+    }
+    sizes.retain(|&size| size > 0);
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes.iter().take(top).product()
+}
+
+fn naive_last_two<T: Coordinate>(boxes: &[P3<T>], metric: Metric) -> Option<T> {
+    let mut circuit: Vec<usize> = (0..boxes.len()).collect();
+    for (_, first, second) in all_pairs(boxes, metric) {
+        relabel(&mut circuit, first, second);
+        if circuit.iter().all(|&label| label == circuit[0]) {
+            return Some(boxes[first].x() * boxes[second].x());
+        }
+    }
+    None
+}
+
+/// A connection between two boxes, `start` being the lesser of the two.
+/// Connections order by distance, then by their endpoints, so that equally
+/// distant connections are always made in the same order.
+#[derive(Eq, Hash, PartialEq, Debug)]
+pub struct Conn<T> {
+    pub dist: T,
+    pub start: P3<T>,
+    pub end: P3<T>,
+}
+impl<T: Copy + Display> Display for Conn<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.start.x(), self.end.x())
+    }
+}
+
+impl<T: Coordinate + Eq> Ord for Conn<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_cmp(other)
+    }
+}
+
+impl<T: Coordinate + Eq> PartialOrd for Conn<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Coordinate> Conn<T> {
+    pub fn new(c1: P3<T>, c2: P3<T>, metric: Metric) -> Self {
+        let dist = metric.distance(&c1, &c2);
+
+        if c1.total_cmp(&c2).is_gt() {
+            Self {
+                start: c2,
+                end: c1,
+                dist,
+            }
+        } else {
+            Self {
+                start: c1,
+                end: c2,
+                dist,
+            }
+        }
+    }
+
+    pub fn contains(&self, coord: P3<T>) -> bool {
+        self.start == coord || self.end == coord
+    }
+
+    /// The order of [`Ord`], for float coordinates too.
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.dist
+            .total_cmp(&other.dist)
+            .then_with(|| self.start.total_cmp(&other.start))
+            .then_with(|| self.end.total_cmp(&other.end))
+    }
+}
+
+/// Merges two lists of pairs of boxes, each already in the order of their
+/// connections.
+fn merge_pairs<T: Coordinate>(
+    boxes: &[P3<T>],
+    metric: Metric,
+    a: &[(usize, usize)],
+    b: &[(usize, usize)],
+) -> Vec<(usize, usize)> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let (mut a, mut b) = (a.iter().peekable(), b.iter().peekable());
+    while let (Some(&&first), Some(&&second)) = (a.peek(), b.peek()) {
+        if pair_order(boxes, metric, first, second).is_le() {
+            merged.push(first);
+            a.next();
+        } else {
+            merged.push(second);
+            b.next();
+        }
+    }
+    merged.extend(a.chain(b));
+    merged
+}
+
+/// Sorts pairs of boxes into the order of their connections.
+fn sort_pairs<T: Coordinate>(boxes: &[P3<T>], metric: Metric, pairs: &mut [(usize, usize)]) {
+    pairs.sort_by(|&a, &b| pair_order(boxes, metric, a, b));
+}
+
+/// What one connection did to the circuits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Event {
+    pub first: usize,
+    pub second: usize,
+    /// Whether the connection joined two circuits, rather than being
+    /// redundant within one.
+    pub merged: bool,
+    /// The size of the circuit holding both boxes afterwards.
+    pub size: usize,
+    /// How many circuits there are afterwards.
+    pub circuits: usize,
+}
+
+/// Boxes, by index, joined into circuits one connection at a time.
+pub struct Circuits {
+    sets: UnionFind,
+    log: Option<Vec<Event>>,
+}
+
+impl Circuits {
+    /// Every one of `len` boxes starts in a circuit of its own.
+    pub fn new(len: usize) -> Self {
+        Self {
+            sets: UnionFind::new(len),
+            log: None,
+        }
+    }
+
+    /// Adds a box in a circuit of its own, and returns its index.
+    pub fn add(&mut self) -> usize {
+        self.sets.push()
+    }
+
+    /// Like [`Circuits::new`], but records an [`Event`] for every
+    /// connection.
+    pub fn with_log(len: usize) -> Self {
+        Self {
+            log: Some(Vec::new()),
+            ..Self::new(len)
+        }
+    }
+
+    /// Joins the circuits of two boxes. Returns `false` if they were already
+    /// one circuit.
+    pub fn connect(&mut self, first: usize, second: usize) -> bool {
+        let merged = self.sets.union(first, second);
+        if let Some(log) = &mut self.log {
+            log.push(Event {
+                first,
+                second,
+                merged,
+                size: self.sets.size_of(first),
+                circuits: self.sets.components(),
+            });
+        }
+        merged
+    }
+
+    /// Every connection made so far, if the circuits keep a log.
+    pub fn log(&self) -> Option<&[Event]> {
+        self.log.as_deref()
+    }
+
+    /// How many separate circuits there are.
+    pub fn count(&self) -> usize {
+        self.sets.components()
+    }
+
+    /// Whether a single circuit holds every box the circuits started with.
+    pub fn is_joined(&self) -> bool {
+        self.count() == 1
+    }
+
+    /// The sizes of the `n` largest circuits, largest first.
+    pub fn largest(&self, n: usize) -> Vec<usize> {
+        let mut sizes = self.sets.sizes();
+        sizes.truncate(n);
+        sizes
+    }
+
+    /// Connects each pair of boxes in turn, stopping at the pair that joins
+    /// every box into one circuit.
+    pub fn connect_all(
+        &mut self,
+        pairs: impl IntoIterator<Item = (usize, usize)>,
+    ) -> Option<(usize, usize)> {
+        pairs
+            .into_iter()
+            .find(|&(first, second)| self.connect(first, second) && self.is_joined())
+    }
+}
+
+/// Multiplies the X coordinates of the two boxes whose connection finally
+/// joins every box into one circuit, however many boxes were parsed.
+pub fn last_two_circuits<T: Coordinate>(boxes: &[P3<T>], metric: Metric) -> Option<T> {
+    let (first, second) =
+        Circuits::new(boxes.len()).connect_all(pairs_by_distance(boxes, metric))?;
+    Some(boxes[first].x() * boxes[second].x())
+}
+
+/// Makes the `connections` closest connections and multiplies the sizes of
+/// the `top` largest circuits.
+pub fn top_circuits<T: Coordinate>(
+    boxes: &[P3<T>],
+    metric: Metric,
+    connections: usize,
+    top: usize,
+) -> usize {
+    let mut circuits = Circuits::new(boxes.len());
+    circuits.connect_all(pairs_by_distance(boxes, metric).take(connections));
+
+    circuits.largest(top).iter().product()
+}
+
+/// Boxes that arrive over time, with circuits kept up to date for a budget
+/// of connections that can change too.
+///
+/// A new box is only measured against the boxes already there: its pairs
+/// are merged into the `budget` closest pairs, and into the spanning tree,
+/// since the spanning tree with the new box only ever needs the old tree's
+/// connections and the new box's own. Raising the budget finds the extra
+/// pairs through the spatial index.
+pub struct CircuitNetwork<T> {
+    boxes: Vec<P3<T>>,
+    metric: Metric,
+    budget: usize,
+    /// The `budget` closest pairs, in connection order.
+    closest: Vec<(usize, usize)>,
+    circuits: Circuits,
+    /// The connections of the minimum spanning tree, in connection order.
+    tree: Vec<(usize, usize)>,
+}
+
+impl<T: Coordinate> CircuitNetwork<T> {
+    pub fn new(metric: Metric, budget: usize) -> Self {
+        Self {
+            boxes: Vec::new(),
+            metric,
+            budget,
+            closest: Vec::new(),
+            circuits: Circuits::new(0),
+            tree: Vec::new(),
+        }
+    }
+
+    pub fn boxes(&self) -> &[P3<T>] {
+        &self.boxes
+    }
+
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Adds a box, connecting it within the budget and the spanning tree.
+    pub fn insert(&mut self, point: P3<T>) {
+        let new = self.boxes.len();
+        self.boxes.push(point);
+        self.circuits.add();
+        let mut pairs: Vec<_> = (0..new).map(|old| (old, new)).collect();
+        sort_pairs(&self.boxes, self.metric, &mut pairs);
+
+        // The new box's pairs can only push the furthest old connections
+        // out of the budget. Only then do the circuits need making afresh.
+        let kept = self.closest.len();
+        self.closest = merge_pairs(&self.boxes, self.metric, &self.closest, &pairs);
+        self.closest.truncate(self.budget);
+        let admitted = self.closest.iter().filter(|pair| pair.1 == new).count();
+        if self.closest.len() - admitted < kept {
+            self.rebuild_circuits();
+        } else {
+            for &(first, second) in self.closest.iter().filter(|pair| pair.1 == new) {
+                self.circuits.connect(first, second);
+            }
+        }
+
+        // The new spanning tree only uses edges of the old one and the new
+        // box's pairs.
+        let candidates = merge_pairs(&self.boxes, self.metric, &self.tree, &pairs);
+        let mut spanning = Circuits::new(self.boxes.len());
+        self.tree = candidates
+            .into_iter()
+            .filter(|&(first, second)| spanning.connect(first, second))
+            .collect();
+    }
+
+    /// Makes the `budget` closest connections instead. Growing it finds
+    /// only the pairs after the ones already connected.
+    pub fn set_budget(&mut self, budget: usize) {
+        if budget > self.closest.len() {
+            let more = budget - self.closest.len();
+            let pairs = pairs_after(&self.boxes, self.metric, self.closest.last().copied());
+            for (first, second) in pairs.take(more) {
+                self.circuits.connect(first, second);
+                self.closest.push((first, second));
+            }
+        } else if budget < self.closest.len() {
+            self.closest.truncate(budget);
+            self.rebuild_circuits();
+        }
+        self.budget = budget;
+    }
+
+    /// Multiplies the sizes of the `top` largest circuits.
+    pub fn top_circuits(&self, top: usize) -> usize {
+        self.circuits.largest(top).iter().product()
+    }
+
+    /// The two boxes, by index, whose connection would finally join every
+    /// box into one circuit.
+    pub fn joining_pair(&self) -> Option<(usize, usize)> {
+        self.tree.last().copied()
+    }
+
+    /// Multiplies the X coordinates of the boxes of [`Self::joining_pair`].
+    pub fn last_two_circuits(&self) -> Option<T> {
+        let (first, second) = self.joining_pair()?;
+        Some(self.boxes[first].x() * self.boxes[second].x())
+    }
+
+    fn rebuild_circuits(&mut self) {
+        self.circuits = Circuits::new(self.boxes.len());
+        for &(first, second) in &self.closest {
+            self.circuits.connect(first, second);
+        }
+    }
+}
+
+/// Every connection, closest first.
+pub fn connections_by_distance<T: Coordinate>(
+    boxes: &[P3<T>],
+    metric: Metric,
+) -> impl Iterator<Item = Conn<T>> {
+    pairs_by_distance(boxes, metric)
+        .map(move |(first, second)| Conn::new(boxes[first], boxes[second], metric))
+}
+
+/// The `count` closest connections, or all of them.
+pub fn close_connections<T: Coordinate>(
+    tuples: &[P3<T>],
+    metric: Metric,
+    count: Option<usize>,
+) -> Vec<Conn<T>> {
+    connections_by_distance(tuples, metric)
+        .take(count.unwrap_or(usize::MAX))
+        .collect()
+}
+
+/// The furthest from zero an integer coordinate may be, so that squared
+/// Euclidean distances between boxes, and products of two coordinates, fit
+/// in an `i64`.
+pub const MAX_COORDINATE: i64 = 876_706_528;
+
+/// Reads integer coordinates when every coordinate is one, and floats when
+/// some coordinate is written with a fraction or an exponent.
+pub fn boxes(input: &str) -> Result<Boxes, ParseError> {
+    let boxes = match tuples::<i64>(input) {
+        Ok(boxes) => boxes,
+        Err(err) if err.found.contains(['.', 'e', 'E']) => {
+            return tuples(input).map(Boxes::Floats);
+        }
+        Err(err) => return Err(err),
+    };
+    for ((index, line), point) in input.lines().enumerate().zip(&boxes) {
+        if let Some(coord) = line
+            .split(',')
+            .zip(point.0)
+            .find_map(|(coord, value)| (value.abs() > MAX_COORDINATE).then_some(coord))
+        {
+            let expected = format!("a coordinate within ±{MAX_COORDINATE}");
+            return Err(ParseError::in_line(Day8::DAY, line, coord, expected).at_line(index + 1));
+        }
+    }
+    Ok(Boxes::Integers(boxes))
+}
+
+pub fn tuples<T: Coordinate>(input: &str) -> Result<Vec<P3<T>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            P3::parse(Day8::DAY, line, "three numbers like '162,817,812'")
+                .map_err(|err| err.at_line(index + 1))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        clustering::{Dendrogram, SpanningTree},
+        generate::{Difficulty, Rng, junction_boxes},
+        point::Point,
+    };
+
+    const EXAMPLE: &str = include_str!("../examples/day8/example.txt");
+
+    fn example() -> Vec<P3<i64>> {
+        tuples(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn test_circuits() {
+        let boxes = example();
+
+        let circuits = top_circuits(&boxes, Metric::Euclidean, 10, 3);
+        assert_eq!(circuits, 40);
+        assert_eq!(top_circuits(&boxes, Metric::Euclidean, 10, 1), 5);
+    }
+
+    #[test]
+    fn test_circuits_count() {
+        let boxes = example();
+        let mut circuits = Circuits::new(boxes.len());
+        assert_eq!(circuits.count(), 20);
+
+        circuits.connect_all(pairs_by_distance(&boxes, Metric::Euclidean).take(10));
+        assert_eq!(circuits.count(), 11);
+        assert_eq!(circuits.largest(4), [5, 4, 2, 2]);
+    }
+
+    #[test]
+    fn test_circuits_log() {
+        let boxes = example();
+        let mut circuits = Circuits::with_log(boxes.len());
+        circuits.connect_all(pairs_by_distance(&boxes, Metric::Euclidean).take(4));
+        let event = |first, second, merged, size, circuits| Event {
+            first,
+            second,
+            merged,
+            size,
+            circuits,
+        };
+        assert_eq!(
+            circuits.log(),
+            Some(
+                &[
+                    event(0, 19, true, 2, 19),
+                    event(0, 7, true, 3, 18),
+                    event(2, 13, true, 2, 17),
+                    event(7, 19, false, 3, 17),
+                ][..]
+            )
+        );
+        assert_eq!(Circuits::new(boxes.len()).log(), None);
+    }
+
+    #[test]
+    fn test_ties_order_by_endpoints() {
+        // Every pair is one apart, so the order is down to the endpoints
+        // alone, whatever order the boxes are listed in.
+        let boxes: Vec<P3<i64>> = tuples("1,0,0\n0,0,0\n0,1,0\n1,1,0").unwrap();
+        let got: Vec<_> = connections_by_distance(&boxes, Metric::Euclidean)
+            .take(4)
+            .map(|conn| (conn.start, conn.end))
+            .collect();
+        let want = [
+            (Point([0, 0, 0]), Point([0, 1, 0])),
+            (Point([0, 0, 0]), Point([1, 0, 0])),
+            (Point([0, 1, 0]), Point([1, 1, 0])),
+            (Point([1, 0, 0]), Point([1, 1, 0])),
+        ];
+        assert_eq!(got, want);
+        assert!(got.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_last_two_circuits() {
+        let boxes = example();
+        assert_eq!(
+            last_two_circuits(&boxes, Metric::Euclidean),
+            Some(216 * 117)
+        );
+        assert_eq!(last_two_circuits(&boxes[..1], Metric::Euclidean), None);
+    }
+
+    #[test]
+    fn test_metrics_agree_with_naive() {
+        for metric in [Metric::Manhattan, Metric::Chebyshev, Metric::Euclidean] {
+            for seed in 0..20 {
+                let input = junction_boxes(&mut Rng::new(seed), 30, Difficulty::Hard);
+                let boxes: Vec<P3<i64>> = tuples(&input).unwrap();
+                assert_eq!(
+                    top_circuits(&boxes, metric, 15, 3),
+                    naive_top(&boxes, metric, 15, 3),
+                    "seed {seed} by {metric}"
+                );
+                assert_eq!(
+                    last_two_circuits(&boxes, metric),
+                    naive_last_two(&boxes, metric),
+                    "seed {seed} by {metric}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_naive_takes_options() {
+        let option = |name: &str, value: &str| (name.to_string(), value.to_string());
+        let options = [option("connections", "10")];
+        assert_eq!(Day8::naive_part_one(EXAMPLE, &options), Some(40));
+        assert_eq!(
+            Day8::naive_part_two(EXAMPLE, &[option("metric", "manhattan")]),
+            Day8::part_two(&configured(EXAMPLE, &[option("metric", "manhattan")]))
+        );
+    }
+
+    #[test]
+    fn test_signed_and_float_boxes() {
+        let input = "-1.5,0,0\n2,0,0\n-0.5,0,0\n10,0.25,-3";
+        let mut playground = Day8::parse(input).unwrap();
+        assert!(matches!(playground.boxes, Boxes::Floats(_)));
+        assert_eq!(Day8::part_two(&playground), Some(Product::Float(20.0)));
+        assert_eq!(Day8::part_two(&playground).unwrap().to_string(), "20");
+
+        Day8::configure(&mut playground, "connections", "2").unwrap();
+        assert_eq!(Day8::part_one(&playground), 3);
+
+        let playground = Day8::parse("-3,0,0\n4,0,0\n-9,-1,0").unwrap();
+        assert!(matches!(playground.boxes, Boxes::Integers(_)));
+        assert_eq!(Day8::part_two(&playground), Some(Product::Integer(-12)));
+
+        let playground = Day8::parse("1e3,0,0\n4,0,0").unwrap();
+        assert!(matches!(playground.boxes, Boxes::Floats(_)));
+    }
+
+    #[test]
+    fn test_coordinates_out_of_range() {
+        let within = "-876706528,0,0\n876706528,876706528,876706528";
+        let playground = Day8::parse(within).unwrap();
+        assert_eq!(
+            Day8::part_two(&playground),
+            Some(Product::Integer(-768_614_336_237_814_784))
+        );
+
+        assert_eq!(
+            Day8::parse("0,0,0\n1,-3037000500,2"),
+            Err(ParseError::new(
+                8,
+                2,
+                3,
+                "-3037000500",
+                "a coordinate within ±876706528"
+            ))
+        );
+        assert_eq!(
+            Day8::parse("0,0,0\n99999999999999999999,0,0"),
+            Err(ParseError::new(8, 2, 1, "99999999999999999999", "a number"))
+        );
+    }
+
+    #[test]
+    fn test_configure_metric() {
+        // Each metric leaves a different connection for last.
+        let mut playground = Day8::parse("2,6,0\n7,0,0\n8,9,0").unwrap();
+        assert_eq!(Day8::part_two(&playground), Some(Product::Integer(2 * 7)));
+        Day8::configure(&mut playground, "metric", "manhattan").unwrap();
+        assert_eq!(Day8::part_two(&playground), Some(Product::Integer(7 * 8)));
+        Day8::configure(&mut playground, "metric", "chebyshev").unwrap();
+        assert_eq!(Day8::part_two(&playground), Some(Product::Integer(2 * 8)));
+        assert!(Day8::configure(&mut playground, "metric", "taxicab").is_err());
+    }
+
+    #[test]
+    fn test_circuit_network() {
+        let mut network = CircuitNetwork::new(Metric::Euclidean, 10);
+        for point in example() {
+            network.insert(point);
+        }
+        assert_eq!(network.top_circuits(3), 40);
+        assert_eq!(network.last_two_circuits(), Some(216 * 117));
+
+        network.set_budget(1000);
+        assert_eq!(network.top_circuits(3), 20);
+        network.set_budget(10);
+        assert_eq!(network.top_circuits(3), 40);
+        assert_eq!(
+            CircuitNetwork::<i64>::new(Metric::Euclidean, 10).joining_pair(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_circuit_network_matches_from_scratch() {
+        for metric in [Metric::Manhattan, Metric::Chebyshev, Metric::Euclidean] {
+            for seed in 0..5 {
+                let mut rng = Rng::new(seed);
+                let input = junction_boxes(&mut rng, 40, Difficulty::Hard);
+                let boxes: Vec<P3<i64>> = tuples(&input).unwrap();
+                let mut network = CircuitNetwork::new(metric, 8);
+                for (len, &point) in (1..).zip(&boxes) {
+                    network.insert(point);
+                    if len % 7 == 0 {
+                        network.set_budget(rng.range(0..=60) as usize);
+                    }
+                    let boxes = &boxes[..len];
+                    let budget = network.budget();
+                    let context = format!("seed {seed}, {len} boxes, budget {budget} by {metric}");
+                    assert_eq!(
+                        network.top_circuits(3),
+                        top_circuits(boxes, metric, budget, 3),
+                        "{context}"
+                    );
+                    assert_eq!(
+                        network.last_two_circuits(),
+                        last_two_circuits(boxes, metric),
+                        "{context}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_spanning_tree() {
+        let boxes = example();
+        let tree = SpanningTree::new(&boxes, Metric::Euclidean);
+        assert_eq!(tree.edges.len(), 19);
+        let last = tree.edges.last().unwrap();
+        assert_eq!(boxes[last.first].x() * boxes[last.second].x(), 216 * 117);
+
+        let dendrogram = Dendrogram::new(&tree);
+        assert_eq!(dendrogram.merges.last().unwrap().size, 20);
+    }
+
+    #[test]
+    fn test_closest_tuples_one() {
+        let metric = Metric::Euclidean;
+        let got = close_connections(&example(), metric, Some(4));
+        let want = Vec::from([
+            Conn::new(Point([162, 817, 812]), Point([425, 690, 689]), metric),
+            Conn::new(Point([162, 817, 812]), Point([431, 825, 988]), metric),
+            Conn::new(Point([906, 360, 560]), Point([805, 96, 715]), metric),
+            Conn::new(Point([431, 825, 988]), Point([425, 690, 689]), metric),
+        ]);
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn test_tuples() {
+        let got = example();
+
+        assert_eq!(got.len(), 20);
+        assert_eq!(*got.first().unwrap(), Point([162, 817, 812]));
+    }
+
+    #[test]
+    fn test_tuples_errors() {
+        assert_eq!(
+            boxes("162,817,812\n57,six,57"),
+            Err(ParseError::new(8, 2, 4, "six", "a number"))
+        );
+        assert_eq!(
+            boxes("162,817"),
+            Err(ParseError::new(
+                8,
+                1,
+                1,
+                "162,817",
+                "three numbers like '162,817,812'"
+            ))
+        );
+    }
+}
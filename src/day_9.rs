@@ -0,0 +1,1086 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops::RangeInclusive,
+};
+
+use crate::{
+    Solution,
+    error::ParseError,
+    grid::Grid,
+    point::{P2, Point},
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<P2<usize>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let corners = tuples(input)?;
+        match diagnose(&corners).error(input) {
+            Some(err) => Err(err),
+            None => Ok(corners),
+        }
+    }
+
+    fn part_one(input: &Self::Input) -> Self::Answer1 {
+        largest_rect(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Option<Self::Answer2> {
+        Some(largest_rect_in_bounds(input))
+    }
+
+    fn naive_part_one(input: &str, _options: &[(String, String)]) -> Option<Self::Answer1> {
+        let corners = tuples(input).unwrap();
+        corners
+            .iter()
+            .flat_map(|first| corners.iter().map(move |second| area(first, second)))
+            .max()
+    }
+
+    /// Tests one tile of each run between corner coordinates, since every
+    /// tile of such a run is in or out together.
+    fn naive_part_two(input: &str, _options: &[(String, String)]) -> Option<Self::Answer2> {
+        let corners = tuples(input).unwrap();
+        // Each corner coordinate, and the one just past it, starts a run.
+        let starts = |coord: fn(&P2<usize>) -> usize| -> Vec<usize> {
+            let mut starts: Vec<usize> = corners
+                .iter()
+                .flat_map(|corner| [coord(corner), coord(corner) + 1])
+                .collect();
+            starts.sort_unstable();
+            starts.dedup();
+            starts
+        };
+        let (xs, ys) = (starts(P2::x), starts(P2::y));
+        let within = |starts: &[usize], a: usize, b: usize| -> Vec<usize> {
+            let (low, high) = (a.min(b), a.max(b));
+            starts
+                .iter()
+                .copied()
+                .filter(|start| (low..=high).contains(start))
+                .collect()
+        };
+        let mut best = 0;
+        for first in &corners {
+            for second in &corners {
+                let area = area(first, second);
+                if area <= best {
+                    continue;
+                }
+                let rows = within(&ys, first.y(), second.y());
+                let covered = within(&xs, first.x(), second.x()).into_iter().all(|x| {
+                    rows.iter()
+                        .all(|&y| tile_in_polygon(&corners, Point([x, y])))
+                });
+                if covered {
+                    best = area;
+                }
+            }
+        }
+        Some(best)
+    }
+}
+
+/// Whether a tile lies on the outline or inside it, by casting a ray to the
+/// right and counting the vertical edges it crosses.
+fn tile_in_polygon(corners: &[P2<usize>], Point([x, y]): P2<usize>) -> bool {
+    let mut inside = false;
+    for (index, &start) in corners.iter().enumerate() {
+        let end = corners[(index + 1) % corners.len()];
+        let (low_x, high_x) = (start.x().min(end.x()), start.x().max(end.x()));
+        let (low_y, high_y) = (start.y().min(end.y()), start.y().max(end.y()));
+        if (low_x..=high_x).contains(&x) && (low_y..=high_y).contains(&y) {
+            return true;
+        }
+        if start.x() == end.x() && start.x() > x && (low_y..high_y).contains(&y) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Which tiles an outline encloses when it winds around them more than once
+/// or crosses itself. For a simple outline both rules agree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// Inside when a ray out crosses the outline an odd number of times.
+    #[default]
+    EvenOdd,
+    /// Inside when the outline winds around the tile at all.
+    NonZero,
+}
+
+impl FillRule {
+    fn encloses(self, winding: i32) -> bool {
+        match self {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+        }
+    }
+}
+
+/// The tiles of row `y` on or inside the outline, as sorted runs that
+/// neither overlap nor touch.
+///
+/// A vertical edge counts as crossing the row when the row is at its top
+/// end or between its ends, but not at its bottom end. A row along a
+/// horizontal edge then counts one of the two vertical edges at its ends if
+/// the outline carries on across the row, and both or neither if it turns
+/// back.
+pub fn row_spans(corners: &[P2<usize>], y: usize, rule: FillRule) -> Vec<RangeInclusive<usize>> {
+    let mut crossings = Vec::new();
+    let mut spans = Vec::new();
+    for (index, &start) in corners.iter().enumerate() {
+        let end = corners[(index + 1) % corners.len()];
+        let (low_x, high_x) = (start.x().min(end.x()), start.x().max(end.x()));
+        let (low_y, high_y) = (start.y().min(end.y()), start.y().max(end.y()));
+        if (low_y..=high_y).contains(&y) {
+            spans.push(low_x..=high_x);
+        }
+        if start.x() == end.x() && (low_y..high_y).contains(&y) {
+            crossings.push((start.x(), if start.y() < end.y() { 1 } else { -1 }));
+        }
+    }
+
+    crossings.sort_unstable();
+    let mut winding = 0;
+    for (index, &(x, delta)) in crossings.iter().enumerate() {
+        winding += delta;
+        if let Some(&(next, _)) = crossings.get(index + 1)
+            && rule.encloses(winding)
+        {
+            spans.push(x..=next);
+        }
+    }
+
+    spans.sort_unstable_by_key(|span| (*span.start(), *span.end()));
+    let mut merged: Vec<RangeInclusive<usize>> = Vec::new();
+    for span in spans {
+        match merged.last_mut() {
+            Some(last) if *span.start() <= last.end() + 1 => {
+                *last = *last.start()..=*last.end().max(span.end());
+            }
+            _ => merged.push(span),
+        }
+    }
+    merged
+}
+
+/// Whether `x` falls in one of `spans`, which must be sorted and must not
+/// overlap, as [`row_spans`] returns them.
+pub fn spans_contain(spans: &[RangeInclusive<usize>], x: usize) -> bool {
+    let index = spans.partition_point(|span| *span.end() < x);
+    spans.get(index).is_some_and(|span| span.contains(&x))
+}
+
+/// Every row from the outline's top corner to its bottom one, with the runs
+/// of tiles on or inside it.
+pub fn rasterise(
+    corners: &[P2<usize>],
+    rule: FillRule,
+) -> Vec<(usize, Vec<RangeInclusive<usize>>)> {
+    let top = corners.iter().map(|corner| corner.y()).min();
+    let bottom = corners.iter().map(|corner| corner.y()).max();
+    match (top, bottom) {
+        (Some(top), Some(bottom)) => (top..=bottom)
+            .map(|y| (y, row_spans(corners, y, rule)))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// The largest rectangle with red tiles at two opposite corners that holds
+/// only red or green tiles.
+pub fn largest_rect_in_bounds(points: &[P2<usize>]) -> usize {
+    let floor = Floor::new(points);
+
+    let mut max_area = 0;
+    for start_point in points.iter() {
+        for end_point in points.iter() {
+            let area = area(start_point, end_point);
+            if area > max_area && floor.covers(*start_point, *end_point) {
+                max_area = area;
+            }
+        }
+    }
+
+    max_area
+}
+
+/// The floor's tiles, compressed into blocks that the outline treats alike:
+/// a row or column for each corner coordinate, and one for each gap between
+/// them. Every tile of a block is on or inside the outline, or none is.
+pub struct Floor {
+    /// The first tile of each column of blocks, followed by the tile just
+    /// past the last one.
+    xs: Vec<usize>,
+    /// Like `xs`, for rows.
+    ys: Vec<usize>,
+    /// Whether each block is on or inside the outline.
+    blocks: Grid<bool>,
+    /// How many blocks are outside the outline above and to the left of
+    /// each block, which makes counting them in any rectangle O(1).
+    outside: Grid<usize>,
+}
+
+impl Floor {
+    pub fn new(corners: &[P2<usize>]) -> Self {
+        let xs = block_starts(corners.iter().map(|corner| corner.x()));
+        let ys = block_starts(corners.iter().map(|corner| corner.y()));
+        let (width, height) = (xs.len().saturating_sub(1), ys.len().saturating_sub(1));
+
+        let mut blocks = Grid::new(width, height, false);
+        for y in 0..height {
+            let spans = row_spans(corners, ys[y], FillRule::EvenOdd);
+            for x in 0..width {
+                blocks[Point([x, y])] = spans_contain(&spans, xs[x]);
+            }
+        }
+
+        let mut outside = Grid::new(width + 1, height + 1, 0);
+        for (Point([x, y]), &inside) in blocks.iter() {
+            outside[Point([x + 1, y + 1])] =
+                usize::from(!inside) + outside[Point([x, y + 1])] + outside[Point([x + 1, y])]
+                    - outside[Point([x, y])];
+        }
+
+        Self {
+            xs,
+            ys,
+            blocks,
+            outside,
+        }
+    }
+
+    /// Whether a tile is on or inside the outline.
+    pub fn contains(&self, Point([x, y]): P2<usize>) -> bool {
+        match (block(&self.xs, x), block(&self.ys, y)) {
+            (Some(x), Some(y)) => self.blocks[Point([x, y])],
+            _ => false,
+        }
+    }
+
+    /// Whether every tile of the rectangle with corners `a` and `b` is on
+    /// or inside the outline, which must have corners at both.
+    pub fn covers(&self, a: P2<usize>, b: P2<usize>) -> bool {
+        let (Some(x1), Some(x2), Some(y1), Some(y2)) = (
+            block(&self.xs, a.x()),
+            block(&self.xs, b.x()),
+            block(&self.ys, a.y()),
+            block(&self.ys, b.y()),
+        ) else {
+            return false;
+        };
+        let (low_x, high_x) = (x1.min(x2), x1.max(x2) + 1);
+        let (low_y, high_y) = (y1.min(y2), y1.max(y2) + 1);
+        let sum = |x, y| self.outside[Point([x, y])];
+        sum(high_x, high_y) + sum(low_x, low_y) == sum(low_x, high_y) + sum(high_x, low_y)
+    }
+}
+
+/// The first tile of each block along an axis, then the tile just past the
+/// last block.
+fn block_starts(coords: impl Iterator<Item = usize>) -> Vec<usize> {
+    let mut coords: Vec<usize> = coords.collect();
+    coords.sort_unstable();
+    coords.dedup();
+    let mut starts = Vec::new();
+    for (index, &coord) in coords.iter().enumerate() {
+        starts.push(coord);
+        if coords.get(index + 1).is_none_or(|&next| next > coord + 1) {
+            starts.push(coord + 1);
+        }
+    }
+    starts
+}
+
+/// The block along an axis holding `tile`, if any does.
+fn block(starts: &[usize], tile: usize) -> Option<usize> {
+    let index = starts.partition_point(|&start| start <= tile);
+    (index > 0 && index < starts.len()).then(|| index - 1)
+}
+
+pub fn tuples(input: &str) -> Result<Vec<P2<usize>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            P2::parse(Day9::DAY, line, "two numbers like '7,1'")
+                .map_err(|err| err.at_line(index + 1))
+        })
+        .collect()
+}
+
+/// Something wrong with an outline, naming corners by their 1-based lines.
+/// Edges run from each corner to the next, and from the last to the first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Defect {
+    /// A corner shares neither its row nor its column with the one before.
+    Diagonal { line: usize, previous: usize },
+    /// A corner repeats the one just before, leaving an edge of length zero.
+    ZeroLength { line: usize, previous: usize },
+    /// A corner repeats one from further back.
+    Duplicate { line: usize, first: usize },
+    /// There are not enough different corners to enclose anything, reported
+    /// at the last line.
+    TooFewCorners { line: usize, count: usize },
+    /// Two edges meet other than at a corner they share, named by the lines
+    /// of their ends, later edge first.
+    Crossing {
+        edge: (usize, usize),
+        other: (usize, usize),
+    },
+}
+
+impl Defect {
+    /// The line to point at: the later corner, or the start of the later
+    /// edge.
+    pub fn line(&self) -> usize {
+        match *self {
+            Defect::Diagonal { line, .. }
+            | Defect::ZeroLength { line, .. }
+            | Defect::Duplicate { line, .. }
+            | Defect::TooFewCorners { line, .. } => line,
+            Defect::Crossing { edge, .. } => edge.0,
+        }
+    }
+}
+
+/// Phrased as what the line should have held, to read as a parse error.
+impl Display for Defect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Defect::Diagonal { previous, .. } => {
+                write!(f, "a corner on the same row or column as line {previous}")
+            }
+            Defect::ZeroLength { previous, .. } => {
+                write!(f, "a corner other than the one on line {previous}")
+            }
+            Defect::Duplicate { first, .. } => {
+                write!(f, "a corner not already on line {first}")
+            }
+            Defect::TooFewCorners { count, .. } => {
+                write!(f, "at least 4 different corners, not {count}")
+            }
+            Defect::Crossing { edge, other } => write!(
+                f,
+                "an edge to line {} that stays clear of the one from line {} to line {}",
+                edge.1, other.0, other.1
+            ),
+        }
+    }
+}
+
+/// Which way an outline runs around, as drawn with rows going down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl Display for Orientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Orientation::Clockwise => write!(f, "clockwise"),
+            Orientation::CounterClockwise => write!(f, "counterclockwise"),
+        }
+    }
+}
+
+/// What [`diagnose`] found: every defect in line order, and the way the
+/// outline runs if it encloses any area.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostics {
+    pub defects: Vec<Defect>,
+    pub orientation: Option<Orientation>,
+}
+
+impl Display for Diagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for defect in &self.defects {
+            writeln!(f, "line {}: expected {defect}", defect.line())?;
+        }
+        match self.orientation {
+            Some(orientation) => write!(f, "runs {orientation}"),
+            None => write!(f, "encloses no area"),
+        }
+    }
+}
+
+impl Diagnostics {
+    /// Every defect as a parse error of `input`, the text the corners were
+    /// read from, with the orientation as a note. Crossings point at the
+    /// coordinate that places the later edge; other defects at the corner.
+    pub fn error(&self, input: &str) -> Option<ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let line = |line: usize| lines.get(line - 1).copied().unwrap_or("");
+        let mut errors = self.defects.iter().map(|defect| {
+            let text = line(defect.line());
+            let part = match *defect {
+                Defect::Crossing { edge, .. } => text
+                    .split(',')
+                    .zip(line(edge.1).split(','))
+                    .find(|(a, b)| a.parse::<usize>() == b.parse::<usize>())
+                    .map_or(text, |(a, _)| a),
+                _ => text,
+            };
+            ParseError::in_line(Day9::DAY, text, part, defect.to_string()).at_line(defect.line())
+        });
+        let first = errors.next()?;
+        let note = match self.orientation {
+            Some(orientation) => format!("the outline runs {orientation}"),
+            None => "the outline encloses no area".to_string(),
+        };
+        Some(first.with_others(errors.collect()).with_note(note))
+    }
+}
+
+/// Checks that the corners make a closed loop of row and column edges that
+/// never meets itself, around at least 4 different corners. A run of corners
+/// along one row or column is fine, as long as it does not turn back on
+/// itself.
+pub fn diagnose(corners: &[P2<usize>]) -> Diagnostics {
+    let mut defects = Vec::new();
+    let mut seen = HashMap::new();
+    // Corners with repeats of the one before dropped, by line.
+    let mut loop_corners: Vec<(usize, P2<usize>)> = Vec::new();
+    for (index, &corner) in corners.iter().enumerate() {
+        let line = index + 1;
+        let previous = if index == 0 { corners.len() } else { index };
+        let before = corners[previous - 1];
+        if corner == before {
+            if corners.len() > 1 {
+                defects.push(Defect::ZeroLength { line, previous });
+            }
+            continue;
+        }
+        if let Some(&first) = seen.get(&corner) {
+            defects.push(Defect::Duplicate { line, first });
+        } else {
+            seen.insert(corner, line);
+        }
+        if corner.x() != before.x() && corner.y() != before.y() {
+            defects.push(Defect::Diagonal { line, previous });
+        }
+        loop_corners.push((line, corner));
+    }
+
+    // Too few corners can only double back, which says nothing more.
+    let count = corners.iter().collect::<HashSet<_>>().len();
+    if count < 4 {
+        defects.push(Defect::TooFewCorners {
+            line: corners.len().max(1),
+            count,
+        });
+        loop_corners.clear();
+    }
+
+    // Diagonal edges are already reported, and have no tiles to meet with.
+    let len = loop_corners.len();
+    let edges: Vec<usize> = (0..len)
+        .filter(|&index| {
+            let (start, end) = (loop_corners[index].1, loop_corners[(index + 1) % len].1);
+            start.x() == end.x() || start.y() == end.y()
+        })
+        .collect();
+    let corner = |index: usize| loop_corners[index % len];
+    for (position, &later) in edges.iter().enumerate() {
+        let ((start_line, start), (end_line, end)) = (corner(later), corner(later + 1));
+        for &earlier in &edges[..position] {
+            let ((other_line, other_start), (other_end_line, other_end)) =
+                (corner(earlier), corner(earlier + 1));
+            let follows = (earlier + 1) % len == later;
+            let precedes = (later + 1) % len == earlier;
+            let meets = if follows || precedes {
+                (follows && turns_back(other_start, start, end))
+                    || (precedes && turns_back(start, end, other_end))
+            } else {
+                let overlap = |a: usize, b: usize, c: usize, d: usize| {
+                    a.min(b) <= c.max(d) && c.min(d) <= a.max(b)
+                };
+                overlap(start.x(), end.x(), other_start.x(), other_end.x())
+                    && overlap(start.y(), end.y(), other_start.y(), other_end.y())
+            };
+            if meets {
+                defects.push(Defect::Crossing {
+                    edge: (start_line, end_line),
+                    other: (other_line, other_end_line),
+                });
+            }
+        }
+    }
+    defects.sort_by_key(Defect::line);
+
+    // Twice the signed area, positive when the outline runs clockwise on a
+    // floor whose rows go down.
+    let twice_area: i128 = (0..corners.len())
+        .map(|index| {
+            let (a, b) = (corners[index], corners[(index + 1) % corners.len()]);
+            a.x() as i128 * b.y() as i128 - b.x() as i128 * a.y() as i128
+        })
+        .sum();
+    let orientation = match twice_area.cmp(&0) {
+        Ordering::Greater => Some(Orientation::Clockwise),
+        Ordering::Less => Some(Orientation::CounterClockwise),
+        Ordering::Equal => None,
+    };
+
+    Diagnostics {
+        defects,
+        orientation,
+    }
+}
+
+/// Whether the outline doubles back along a row or column at `corner`.
+fn turns_back(before: P2<usize>, corner: P2<usize>, after: P2<usize>) -> bool {
+    let back = |from: usize, at: usize, to: usize| (from < at && to < at) || (from > at && to > at);
+    (before.y() == corner.y() && corner.y() == after.y() && back(before.x(), corner.x(), after.x()))
+        || (before.x() == corner.x()
+            && corner.x() == after.x()
+            && back(before.y(), corner.y(), after.y()))
+}
+
+fn area(p1: &P2<usize>, p2: &P2<usize>) -> usize {
+    (p1.x().abs_diff(p2.x()) + 1) * (p1.y().abs_diff(p2.y()) + 1)
+}
+
+pub fn largest_rect(points: &[P2<usize>]) -> usize {
+    let mut max_area = 0;
+
+    for start_point in points.iter() {
+        for end_point in points.iter() {
+            let area = area(start_point, end_point);
+            if area > max_area {
+                max_area = area;
+            }
+        }
+    }
+
+    max_area
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::generate::{Difficulty, Rng, polygon};
+
+    fn points(tuples: Vec<(usize, usize)>) -> Vec<P2<usize>> {
+        tuples.into_iter().map(|(x, y)| Point([x, y])).collect()
+    }
+
+    /// The tiles that pass `inside`, up to one past the furthest corner.
+    fn tiles(corners: &[P2<usize>], inside: impl Fn(P2<usize>) -> bool) -> HashSet<(usize, usize)> {
+        let width = corners
+            .iter()
+            .map(|corner| corner.x() + 2)
+            .max()
+            .unwrap_or(0);
+        let height = corners
+            .iter()
+            .map(|corner| corner.y() + 2)
+            .max()
+            .unwrap_or(0);
+        (0..width)
+            .flat_map(|x| (0..height).map(move |y| (x, y)))
+            .filter(|&(x, y)| inside(Point([x, y])))
+            .collect()
+    }
+
+    fn floor_tiles(input: Vec<(usize, usize)>) -> HashSet<(usize, usize)> {
+        let corners = points(input);
+        let floor = Floor::new(&corners);
+        tiles(&corners, |tile| floor.contains(tile))
+    }
+
+    const EXAMPLE: &str = include_str!("../examples/day9/example.txt");
+
+    #[test]
+    fn test_floor_flat() {
+        let input = vec![(0, 1), (0, 4)];
+        let want = HashSet::from([(0, 1), (0, 2), (0, 3), (0, 4)]);
+
+        assert_eq!(want, floor_tiles(input));
+    }
+
+    #[test]
+    fn test_floor_vertical() {
+        let input = vec![(1, 0), (4, 0)];
+        let want = HashSet::from([(1, 0), (2, 0), (3, 0), (4, 0)]);
+
+        assert_eq!(want, floor_tiles(input));
+    }
+
+    #[test]
+    fn test_floor_square() {
+        let input = vec![(0, 0), (2, 0), (2, 2), (0, 2)];
+        let want = HashSet::from([
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+        ]);
+        assert_eq!(want, floor_tiles(input));
+    }
+
+    #[test]
+    fn test_floor_m_shape() {
+        let input = vec![
+            (0, 0),
+            (2, 0),
+            (2, 1),
+            (1, 1),
+            (1, 3),
+            (2, 3),
+            (2, 4),
+            (0, 4),
+        ];
+        let want = HashSet::from([
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (0, 2),
+            (1, 2),
+            (0, 3),
+            (1, 3),
+            (2, 3),
+            (0, 4),
+            (1, 4),
+            (2, 4),
+        ]);
+        assert_eq!(want, floor_tiles(input));
+    }
+
+    #[test]
+    fn test_floor_example() {
+        let input = vec![
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ];
+        let want = HashSet::from([
+            (7, 1),
+            (8, 1),
+            (9, 1),
+            (10, 1),
+            (11, 1),
+            (7, 2),
+            (8, 2),
+            (9, 2),
+            (10, 2),
+            (11, 2),
+            (7, 3),
+            (8, 3),
+            (9, 3),
+            (10, 3),
+            (11, 3),
+            (2, 3),
+            (3, 3),
+            (4, 3),
+            (5, 3),
+            (6, 3),
+            (2, 4),
+            (3, 4),
+            (4, 4),
+            (5, 4),
+            (6, 4),
+            (7, 4),
+            (8, 4),
+            (9, 4),
+            (10, 4),
+            (11, 4),
+            (2, 5),
+            (3, 5),
+            (4, 5),
+            (5, 5),
+            (6, 5),
+            (7, 5),
+            (8, 5),
+            (9, 5),
+            (10, 5),
+            (11, 5),
+            (9, 6),
+            (10, 6),
+            (11, 6),
+            (9, 7),
+            (10, 7),
+            (11, 7),
+        ]);
+        assert_eq!(want, floor_tiles(input));
+    }
+
+    #[test]
+    fn test_floor_agrees_with_ray_casting() {
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let corners = tuples(&polygon(&mut rng, 12, Difficulty::Easy)).unwrap();
+            let floor = Floor::new(&corners);
+            assert_eq!(
+                tiles(&corners, |tile| tile_in_polygon(&corners, tile)),
+                tiles(&corners, |tile| floor.contains(tile)),
+                "seed {seed}"
+            );
+        }
+    }
+
+    /// Draws the rasterised rows, with `#` for tiles on or inside the
+    /// outline.
+    fn drawing(input: Vec<(usize, usize)>, rule: FillRule) -> String {
+        let corners = points(input);
+        let width = corners
+            .iter()
+            .map(|corner| corner.x() + 1)
+            .max()
+            .unwrap_or(0);
+        let mut drawing = String::new();
+        for (_, spans) in rasterise(&corners, rule) {
+            let row: String = (0..width)
+                .map(|x| if spans_contain(&spans, x) { '#' } else { '.' })
+                .collect();
+            drawing.push_str(row.trim_end_matches('.'));
+            drawing.push('\n');
+        }
+        drawing
+    }
+
+    #[test]
+    fn test_spans_contain() {
+        let spans = [2..=4, 7..=7, 9..=12];
+        let got: Vec<usize> = (0..14).filter(|&x| spans_contain(&spans, x)).collect();
+        assert_eq!(got, [2, 3, 4, 7, 9, 10, 11, 12]);
+        assert!(!spans_contain(&[], 0));
+    }
+
+    #[test]
+    fn test_rasterise_example() {
+        let input = vec![
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ];
+        let want = "\
+.......#####
+.......#####
+..##########
+..##########
+..##########
+.........###
+.........###
+";
+        assert_eq!(drawing(input, FillRule::EvenOdd), want);
+    }
+
+    #[test]
+    fn test_rasterise_concave() {
+        // A U whose prongs end on the same row, so the top row passes
+        // through four corners.
+        let input = vec![
+            (0, 0),
+            (2, 0),
+            (2, 3),
+            (5, 3),
+            (5, 0),
+            (7, 0),
+            (7, 5),
+            (0, 5),
+        ];
+        let want = "\
+###..###
+###..###
+###..###
+########
+########
+########
+";
+        assert_eq!(drawing(input, FillRule::EvenOdd), want);
+    }
+
+    #[test]
+    fn test_rasterise_collinear_runs() {
+        // Extra corners partway along the top and right edges.
+        let input = vec![(0, 0), (3, 0), (6, 0), (6, 2), (6, 4), (0, 4)];
+        let want = "\
+#######
+#######
+#######
+#######
+#######
+";
+        assert_eq!(drawing(input, FillRule::EvenOdd), want);
+    }
+
+    #[test]
+    fn test_rasterise_steps() {
+        // A staircase, where each row touches a corner on both sides.
+        let input = vec![
+            (0, 0),
+            (2, 0),
+            (2, 2),
+            (4, 2),
+            (4, 4),
+            (6, 4),
+            (6, 6),
+            (0, 6),
+        ];
+        let want = "\
+###
+###
+#####
+#####
+#######
+#######
+#######
+";
+        assert_eq!(drawing(input, FillRule::EvenOdd), want);
+    }
+
+    #[test]
+    fn test_rasterise_overlapping_loop() {
+        // An outline that crosses itself and winds twice around the middle.
+        let input = vec![
+            (0, 0),
+            (4, 0),
+            (4, 4),
+            (2, 4),
+            (2, 2),
+            (6, 2),
+            (6, 6),
+            (0, 6),
+        ];
+        let even_odd = "\
+#####
+#####
+#######
+###.###
+#######
+#######
+#######
+";
+        let non_zero = "\
+#####
+#####
+#######
+#######
+#######
+#######
+#######
+";
+        assert_eq!(drawing(input.clone(), FillRule::EvenOdd), even_odd);
+        assert_eq!(drawing(input, FillRule::NonZero), non_zero);
+    }
+
+    fn diagnosis(input: &str) -> Diagnostics {
+        diagnose(&tuples(input).unwrap())
+    }
+
+    #[test]
+    fn test_diagnose_example() {
+        assert_eq!(
+            diagnosis(EXAMPLE),
+            Diagnostics {
+                defects: vec![],
+                orientation: Some(Orientation::Clockwise),
+            }
+        );
+        let reversed: Vec<&str> = EXAMPLE.lines().rev().collect();
+        assert_eq!(
+            diagnosis(&reversed.join("\n")).orientation,
+            Some(Orientation::CounterClockwise)
+        );
+    }
+
+    #[test]
+    fn test_diagnose_steps() {
+        assert_eq!(
+            diagnosis("7,1\n11,1\n11,7\n8,6\n").defects,
+            [
+                Defect::Diagonal {
+                    line: 1,
+                    previous: 4
+                },
+                Defect::Diagonal {
+                    line: 4,
+                    previous: 3
+                },
+            ]
+        );
+        assert_eq!(
+            diagnosis("0,0\n4,0\n4,0\n4,4\n0,4\n").defects,
+            [Defect::ZeroLength {
+                line: 3,
+                previous: 2
+            }]
+        );
+        // A run of corners along one edge is fine.
+        assert_eq!(diagnosis("0,0\n2,0\n4,0\n4,4\n0,4\n").defects, []);
+    }
+
+    #[test]
+    fn test_diagnose_duplicate() {
+        // Two squares joined at a corner, which both loops pass through.
+        let diagnostics = diagnosis("0,0\n2,0\n2,2\n4,2\n4,4\n2,4\n2,2\n0,2\n");
+        let crossing = |edge, other| Defect::Crossing { edge, other };
+        assert_eq!(
+            diagnostics.defects,
+            [
+                crossing((6, 7), (2, 3)),
+                crossing((6, 7), (3, 4)),
+                Defect::Duplicate { line: 7, first: 3 },
+                crossing((7, 8), (2, 3)),
+                crossing((7, 8), (3, 4)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diagnose_crossings() {
+        assert_eq!(
+            diagnosis("0,0\n4,0\n4,4\n2,4\n2,2\n6,2\n6,6\n0,6\n").defects,
+            [Defect::Crossing {
+                edge: (5, 6),
+                other: (2, 3)
+            }]
+        );
+        // Turning back along the top edge folds the outline onto itself.
+        assert_eq!(
+            diagnosis("0,0\n4,0\n2,0\n2,3\n0,3\n").defects,
+            [
+                Defect::Crossing {
+                    edge: (2, 3),
+                    other: (1, 2)
+                },
+                Defect::Crossing {
+                    edge: (3, 4),
+                    other: (1, 2)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diagnostics_display() {
+        assert_eq!(
+            diagnosis("0,0\n4,0\n4,0\n4,4\n1,3\n").to_string(),
+            "line 1: expected a corner on the same row or column as line 5
+line 3: expected a corner other than the one on line 2
+line 5: expected a corner on the same row or column as line 4
+runs clockwise"
+        );
+        assert_eq!(
+            diagnosis("3,3\n").to_string(),
+            "line 1: expected at least 4 different corners, not 1
+encloses no area"
+        );
+    }
+
+    #[test]
+    fn test_diagnose_too_few_corners() {
+        assert_eq!(
+            diagnosis("0,0\n4,0\n"),
+            Diagnostics {
+                defects: vec![Defect::TooFewCorners { line: 2, count: 2 }],
+                orientation: None,
+            }
+        );
+        assert_eq!(
+            diagnosis("0,0\n4,0\n4,4\n").defects,
+            [
+                Defect::Diagonal {
+                    line: 1,
+                    previous: 3
+                },
+                Defect::TooFewCorners { line: 3, count: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_defects() {
+        let locations = |err: &ParseError| {
+            let others = err.others.iter().map(|other| (other.line, other.column));
+            [(err.line, err.column)]
+                .into_iter()
+                .chain(others)
+                .collect::<Vec<_>>()
+        };
+
+        let err = Day9::parse("7,1\n11,1\n11,7\n7,1\n7,3\n").unwrap_err();
+        assert_eq!(err.expected, "a corner not already on line 1");
+        assert_eq!(locations(&err), [(4, 1), (4, 1), (4, 1), (5, 1)]);
+        assert_eq!(err.notes, ["the outline runs clockwise"]);
+
+        let err = Day9::parse("0,0\n4,0\n4,4\n2,4\n2,2\n6,2\n6,6\n0,6\n").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(
+                9,
+                5,
+                3,
+                "2",
+                "an edge to line 6 that stays clear of the one from line 2 to line 3"
+            )
+            .with_note("the outline runs clockwise")
+        );
+
+        assert_eq!(
+            Day9::parse("0,0\n4,0\n"),
+            Err(
+                ParseError::new(9, 2, 1, "4,0", "at least 4 different corners, not 2")
+                    .with_note("the outline encloses no area")
+            )
+        );
+    }
+
+    #[test]
+    fn test_tuples_errors() {
+        assert_eq!(
+            tuples("7,1\n11,1,4"),
+            Err(ParseError::new(9, 2, 1, "11,1,4", "two numbers like '7,1'"))
+        );
+        assert_eq!(
+            tuples("7,1\n11,x"),
+            Err(ParseError::new(9, 2, 4, "x", "a number"))
+        );
+    }
+
+    #[test]
+    fn test_largest_rect() {
+        let input = EXAMPLE;
+
+        assert_eq!(largest_rect(&tuples(input).unwrap()), 50);
+    }
+
+    #[test]
+    fn test_largest_fitting_rect() {
+        let input = EXAMPLE;
+
+        assert_eq!(largest_rect_in_bounds(&tuples(input).unwrap()), 24);
+    }
+}
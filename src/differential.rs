@@ -0,0 +1,211 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{
+    generate::{self, Difficulty, Params},
+    registry::Puzzle,
+};
+
+pub const DEFAULT_CASES: usize = 2000;
+pub const DEFAULT_MAX_SIZE: usize = 12;
+
+/// A generated input on which a day's solver and its naive reference
+/// disagree. Answers are `None` when that side panicked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub params: Params,
+    /// Puzzle parameters as `(name, value)`, set on both sides.
+    pub options: Vec<(String, String)>,
+    pub input: String,
+    pub fast: Option<String>,
+    pub naive: Option<String>,
+}
+
+/// Runs `cases` generated inputs through both the solver and the naive
+/// reference, cycling sizes from 1 to `max_size` and counting seeds up from
+/// `seed`. Each input is asked with the day's [`generate::options`], then
+/// `options`, which can override them. Returns the mismatch with the
+/// shortest input, if there is one.
+pub fn check_day(
+    puzzle: &dyn Puzzle,
+    parts: &[u8],
+    options: &[(String, String)],
+    cases: usize,
+    seed: u64,
+    max_size: usize,
+    difficulty: Difficulty,
+) -> Option<Mismatch> {
+    let day = puzzle.day();
+    let mut smallest: Option<Mismatch> = None;
+    for case in 0..cases {
+        let params = Params {
+            seed: seed.wrapping_add(case as u64),
+            size: case % max_size.max(1) + 1,
+            difficulty,
+        };
+        let input = generate::generate(day, &params)?;
+        if smallest
+            .as_ref()
+            .is_some_and(|smallest| smallest.input.len() <= input.len())
+        {
+            continue;
+        }
+
+        let mut case_options = generate::options(day, &params);
+        case_options.extend_from_slice(options);
+        for &part in parts {
+            if let Some((fast, naive)) = compare(puzzle, &input, &case_options, part) {
+                smallest = Some(Mismatch {
+                    day,
+                    part,
+                    params,
+                    options: case_options,
+                    input,
+                    fast,
+                    naive,
+                });
+                break;
+            }
+        }
+    }
+    smallest
+}
+
+/// Returns both answers if the solver and the naive reference disagree on
+/// `part` with `options` set. Parts without a naive reference or without a
+/// solver always agree.
+pub fn compare(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    options: &[(String, String)],
+    part: u8,
+) -> Option<(Option<String>, Option<String>)> {
+    let naive = panic::catch_unwind(AssertUnwindSafe(|| {
+        puzzle.solve_naive(input, options, part)
+    }));
+    if matches!(naive, Ok(None)) {
+        return None;
+    }
+    let fast = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut parsed = puzzle.parse(input).ok()?;
+        for (name, value) in options {
+            puzzle.configure(parsed.as_mut(), name, value).ok()?;
+        }
+        puzzle.solve(parsed.as_ref(), part)
+    }));
+    if matches!(fast, Ok(None)) {
+        return None;
+    }
+
+    let (fast, naive) = (fast.ok().flatten(), naive.ok().flatten());
+    (fast.is_none() || fast != naive).then_some((fast, naive))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Solution, day_1,
+        day_8::{self, Playground},
+        error::ParseError,
+        registry,
+        registry::DAYS,
+    };
+
+    #[test]
+    fn test_days_agree_with_naive() {
+        for puzzle in DAYS {
+            let mismatch = check_day(puzzle, &[1, 2], &[], 200, 0, 12, Difficulty::Easy);
+            assert_eq!(mismatch, None);
+        }
+    }
+
+    #[test]
+    fn test_day_9_agrees_on_hard_floors() {
+        let puzzle = registry::find(9).unwrap();
+        assert_eq!(
+            check_day(puzzle, &[1, 2], &[], 50, 0, 12, Difficulty::Hard),
+            None
+        );
+    }
+
+    /// Day 1 with a dial that forgets to count left turns landing on zero.
+    struct Broken;
+
+    impl Solution for Broken {
+        const DAY: u8 = 1;
+
+        type Input = Vec<day_1::Turn>;
+        type Answer1 = i32;
+        type Answer2 = i64;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input.lines().map(day_1::parse_turn).collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Self::Answer1 {
+            day_1::get_pass(input)
+        }
+
+        fn part_two(input: &Self::Input) -> Option<Self::Answer2> {
+            let turns: Vec<_> = input
+                .iter()
+                .map(|&turn| match turn {
+                    day_1::Turn::Left(num) if num > 1 => day_1::Turn::Left(num - 1),
+                    turn => turn,
+                })
+                .collect();
+            Some(day_1::get_pass_complex(&turns))
+        }
+
+        fn naive_part_two(input: &str, options: &[(String, String)]) -> Option<Self::Answer2> {
+            day_1::Day1::naive_part_two(input, options)
+        }
+    }
+
+    /// Day 8 ignoring how many connections it was asked to make.
+    struct Unconfigured;
+
+    impl Solution for Unconfigured {
+        const DAY: u8 = 8;
+
+        type Input = Playground;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            <day_8::Day8 as Solution>::parse(input)
+        }
+
+        fn part_one(input: &Self::Input) -> Self::Answer1 {
+            day_8::Day8::part_one(&Playground {
+                connections: day_8::DEFAULT_CONNECTIONS,
+                ..input.clone()
+            })
+        }
+
+        fn naive_part_one(input: &str, options: &[(String, String)]) -> Option<Self::Answer1> {
+            day_8::Day8::naive_part_one(input, options)
+        }
+
+        fn configure(input: &mut Self::Input, name: &str, value: &str) -> Result<(), String> {
+            <day_8::Day8 as Solution>::configure(input, name, value)
+        }
+    }
+
+    #[test]
+    fn test_day_8_checks_connections() {
+        let mismatch = check_day(&Unconfigured, &[1], &[], 200, 0, 12, Difficulty::Easy).unwrap();
+        assert_eq!(mismatch.options[0].0, "connections");
+    }
+
+    #[test]
+    fn test_reports_smallest_mismatch() {
+        let mismatch = check_day(&Broken, &[1, 2], &[], 500, 0, 10, Difficulty::Hard).unwrap();
+
+        assert_eq!(mismatch.part, 2);
+        assert_eq!(mismatch.input.lines().count(), 1);
+        assert_ne!(mismatch.fast, mismatch.naive);
+    }
+}
@@ -0,0 +1,172 @@
+use std::{error::Error, fmt::Display};
+
+/// An input that does not match what a day's parser expects. Lines and
+/// columns are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub found: String,
+    pub expected: String,
+    /// Further errors in the same input, for parsers that check all of it
+    /// before giving up.
+    pub others: Vec<ParseError>,
+    /// Context shown after the errors.
+    pub notes: Vec<String>,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u8,
+        line: usize,
+        column: usize,
+        found: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            found: found.into(),
+            expected: expected.into(),
+            others: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    /// Builds an error for `part`, which must be a slice of `line`, taking the
+    /// column from where `part` starts. The line number defaults to 1 and is
+    /// meant to be fixed up with [`ParseError::at_line`] by the caller that
+    /// knows it.
+    pub fn in_line(day: u8, line: &str, part: &str, expected: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+        let column = line[..offset.min(line.len())].chars().count() + 1;
+        Self::new(day, 1, column, part, expected)
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn with_others(mut self, others: Vec<ParseError>) -> Self {
+        self.others = others;
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    /// Renders the error with the offending line of `source` and a marker
+    /// under the bad text, then any further errors and the notes.
+    pub fn diagnostic(&self, source: &str) -> String {
+        let mut out = self.marked(source);
+        for other in &self.others {
+            out.push('\n');
+            out.push_str(&other.marked(source));
+        }
+        for note in &self.notes {
+            out.push_str("\nnote: ");
+            out.push_str(note);
+        }
+        out
+    }
+
+    fn marked(&self, source: &str) -> String {
+        let text = source
+            .lines()
+            .nth(self.line.saturating_sub(1))
+            .unwrap_or("");
+        let gutter = " ".repeat(self.line.to_string().len());
+        let width = self.found.chars().count().max(1);
+        format!(
+            "error: {}\n{gutter} |\n{} | {text}\n{gutter} | {}{}",
+            self.headline(),
+            self.line,
+            " ".repeat(self.column.saturating_sub(1)),
+            "^".repeat(width),
+        )
+    }
+
+    /// The error itself, leaving out any others.
+    fn headline(&self) -> String {
+        format!(
+            "day {} line {} column {}: expected {}, found {:?}",
+            self.day, self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.headline())?;
+        match self.others.len() {
+            0 => Ok(()),
+            1 => write!(f, " (and 1 more error)"),
+            more => write!(f, " (and {more} more errors)"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_line_column() {
+        let line = "12,x4,7";
+        let part = &line[3..5];
+        let got = ParseError::in_line(8, line, part, "a number").at_line(4);
+
+        assert_eq!(got, ParseError::new(8, 4, 4, "x4", "a number"));
+    }
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(1, 3, 2, "x", "a turn distance");
+        assert_eq!(
+            err.to_string(),
+            "day 1 line 3 column 2: expected a turn distance, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let source = "L68\nL30\nRx8\nL5";
+        let err = ParseError::new(1, 3, 2, "x8", "a turn distance");
+        let want = "error: day 1 line 3 column 2: expected a turn distance, found \"x8\"
+  |
+3 | Rx8
+  |  ^^";
+
+        assert_eq!(err.diagnostic(source), want);
+    }
+
+    #[test]
+    fn test_diagnostic_others_and_notes() {
+        let source = "L68\nLx0\nRx8";
+        let err = ParseError::new(1, 2, 2, "x0", "a turn distance")
+            .with_others(vec![ParseError::new(1, 3, 2, "x8", "a turn distance")])
+            .with_note("turns start at 50");
+        let want = "error: day 1 line 2 column 2: expected a turn distance, found \"x0\"
+  |
+2 | Lx0
+  |  ^^
+error: day 1 line 3 column 2: expected a turn distance, found \"x8\"
+  |
+3 | Rx8
+  |  ^^
+note: turns start at 50";
+
+        assert_eq!(err.diagnostic(source), want);
+        assert_eq!(
+            err.to_string(),
+            "day 1 line 2 column 2: expected a turn distance, found \"x0\" (and 1 more error)"
+        );
+    }
+}
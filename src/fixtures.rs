@@ -0,0 +1,276 @@
+use std::{
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use crate::{answers, registry};
+
+/// Example inputs live in `examples/dayN/<name>.txt`, each next to a
+/// `<name>.expected` file listing the answers it should produce:
+///
+/// ```text
+/// part1 = 3
+/// part2 = 6
+/// ```
+///
+/// Parts without an expected answer are not checked. Lines like
+/// `set.connections = 10` set a puzzle parameter before solving, the way
+/// `aoc run --set` does.
+pub const EXAMPLES_DIR: &str = "examples";
+const INPUT_EXTENSION: &str = "txt";
+const EXPECTED_EXTENSION: &str = "expected";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub day: u8,
+    pub path: PathBuf,
+    pub input: String,
+    pub expected: [Option<String>; 2],
+    /// Puzzle parameters as `(name, value)`, set in order after parsing.
+    pub options: Vec<(String, String)>,
+}
+
+/// The contents of a sidecar file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Sidecar {
+    pub expected: [Option<String>; 2],
+    pub options: Vec<(String, String)>,
+}
+
+/// Finds every fixture under `dir`, sorted by day and then file name.
+pub fn discover(dir: &Path) -> io::Result<Vec<Fixture>> {
+    let mut fixtures = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let day_dir = entry?.path();
+        let Some(day) = day_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day")?.parse::<u8>().ok())
+        else {
+            continue;
+        };
+
+        for entry in fs::read_dir(&day_dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == INPUT_EXTENSION) {
+                fixtures.push(load(day, path)?);
+            }
+        }
+    }
+
+    fixtures.sort_by(|lhs, rhs| (lhs.day, &lhs.path).cmp(&(rhs.day, &rhs.path)));
+    Ok(fixtures)
+}
+
+fn load(day: u8, path: PathBuf) -> io::Result<Fixture> {
+    let input = fs::read_to_string(&path)?;
+    let expected_path = path.with_extension(EXPECTED_EXTENSION);
+    let sidecar = match fs::read_to_string(&expected_path) {
+        Ok(text) => parse_expected(&text).map_err(|err| {
+            let message = format!("{}:{err}", expected_path.display());
+            io::Error::new(io::ErrorKind::InvalidData, message)
+        })?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Sidecar::default(),
+        Err(err) => return Err(err),
+    };
+
+    Ok(Fixture {
+        day,
+        path,
+        input,
+        expected: sidecar.expected,
+        options: sidecar.options,
+    })
+}
+
+/// Parses a sidecar file. Errors carry the 1-based line number, as
+/// `"<line>: <message>"`.
+pub fn parse_expected(text: &str) -> Result<Sidecar, String> {
+    let mut sidecar = Sidecar::default();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line_number = index + 1;
+        let (key, value) = line
+            .split_once('=')
+            .ok_or(format!("{line_number}: expected part1 = <answer>"))?;
+        let value = answers::parse_value(value.trim()).ok_or(format!(
+            "{line_number}: expected a quoted string or integer"
+        ))?;
+        match key.trim() {
+            "part1" => sidecar.expected[0] = Some(value),
+            "part2" => sidecar.expected[1] = Some(value),
+            key => match key.strip_prefix("set.") {
+                Some(option) => sidecar.options.push((option.to_string(), value)),
+                None => return Err(format!("{line_number}: unknown key {key:?}")),
+            },
+        }
+    }
+    Ok(sidecar)
+}
+
+/// Writes `input` as `examples/dayN/<name>.txt` under `dir`, with a sidecar
+/// holding the options to set and the given answers. An existing example is
+/// only replaced when `force` is set. Returns the input's path.
+pub fn write(
+    dir: &Path,
+    day: u8,
+    name: &str,
+    input: &str,
+    options: &[(String, String)],
+    expected: &[Option<String>; 2],
+    force: bool,
+) -> io::Result<PathBuf> {
+    let day_dir = dir.join(format!("day{day}"));
+    fs::create_dir_all(&day_dir)?;
+
+    // Appended rather than set, so a name like `ex.1` keeps its dot.
+    let path = day_dir.join(format!("{name}.{INPUT_EXTENSION}"));
+    if path.exists() && !force {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!(
+                "{} already exists; pass --force to replace it",
+                path.display()
+            ),
+        ));
+    }
+    fs::write(&path, input)?;
+
+    let options = options
+        .iter()
+        .map(|(option, value)| format!("set.{option} = {value:?}\n"));
+    let answers = expected.iter().enumerate().filter_map(|(index, answer)| {
+        Some(format!("part{} = {:?}\n", index + 1, answer.as_ref()?))
+    });
+    let sidecar: String = options.chain(answers).collect();
+    fs::write(
+        day_dir.join(format!("{name}.{EXPECTED_EXTENSION}")),
+        sidecar,
+    )?;
+    Ok(path)
+}
+
+/// Runs the fixture through its day's solver and describes every mismatch,
+/// parse error or panic. An empty result means the fixture passed.
+pub fn check(fixture: &Fixture) -> Vec<String> {
+    let name = fixture.path.display();
+    let Some(puzzle) = registry::find(fixture.day) else {
+        return vec![format!("{name}: no solver for day {}", fixture.day)];
+    };
+
+    let mut parsed = match panic::catch_unwind(AssertUnwindSafe(|| puzzle.parse(&fixture.input))) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(err)) => return vec![format!("{name}: {err}")],
+        Err(_) => return vec![format!("{name}: parse panicked")],
+    };
+    for (option, value) in &fixture.options {
+        if let Err(err) = puzzle.configure(parsed.as_mut(), option, value) {
+            return vec![format!("{name}: {err}")];
+        }
+    }
+
+    let mut failures = Vec::new();
+    for (part, expected) in (1..=2).zip(&fixture.expected) {
+        let Some(expected) = expected else {
+            continue;
+        };
+        match panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve(parsed.as_ref(), part))) {
+            Ok(Some(got)) if got == *expected => {}
+            Ok(Some(got)) => failures.push(format!(
+                "{name} part {part}: expected {expected}, got {got}"
+            )),
+            Ok(None) => failures.push(format!("{name} part {part}: no solver")),
+            Err(_) => failures.push(format!("{name} part {part}: panicked")),
+        }
+    }
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expected() {
+        let got = parse_expected("# from the puzzle text\npart1 = 3\npart2 = \"6\"\n");
+        let want = [Some("3".to_string()), Some("6".to_string())];
+        assert_eq!(got.map(|sidecar| sidecar.expected), Ok(want));
+
+        let got = parse_expected("part2 = 40");
+        let want = [None, Some("40".to_string())];
+        assert_eq!(got.map(|sidecar| sidecar.expected), Ok(want));
+
+        let got = parse_expected("set.connections = 10\npart1 = 40");
+        assert_eq!(
+            got,
+            Ok(Sidecar {
+                expected: [Some("40".to_string()), None],
+                options: vec![("connections".to_string(), "10".to_string())],
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_expected_errors() {
+        assert_eq!(
+            parse_expected("part3 = 1"),
+            Err("1: unknown key \"part3\"".to_string())
+        );
+        assert_eq!(
+            parse_expected("\npart1 3"),
+            Err("2: expected part1 = <answer>".to_string())
+        );
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = std::env::temp_dir().join(format!("aoc-fixtures-{}", std::process::id()));
+        let expected = [Some("3".to_string()), None];
+        let options = [("top".to_string(), "2".to_string())];
+
+        let path = write(&dir, 8, "ex.1", "1,2,3\n", &options, &expected, false).unwrap();
+        assert_eq!(path, dir.join("day8/ex.1.txt"));
+        let sidecar = fs::read_to_string(dir.join("day8/ex.1.expected")).unwrap();
+        assert_eq!(sidecar, "set.top = \"2\"\npart1 = \"3\"\n");
+
+        let err = write(&dir, 8, "ex.1", "4,5,6\n", &[], &expected, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1,2,3\n");
+
+        write(&dir, 8, "ex.1", "4,5,6\n", &[], &expected, true).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "4,5,6\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_check_reports_mismatch() {
+        let fixture = Fixture {
+            day: 1,
+            path: PathBuf::from("examples/day1/wrong.txt"),
+            input: "L50\n".to_string(),
+            expected: [Some("1".to_string()), Some("2".to_string())],
+            options: Vec::new(),
+        };
+
+        let want = vec!["examples/day1/wrong.txt part 2: expected 2, got 1".to_string()];
+        assert_eq!(check(&fixture), want);
+    }
+
+    #[test]
+    fn test_check_reports_unknown_option() {
+        let fixture = Fixture {
+            day: 1,
+            path: PathBuf::from("examples/day1/option.txt"),
+            input: "L50\n".to_string(),
+            expected: [Some("1".to_string()), None],
+            options: vec![("top".to_string(), "3".to_string())],
+        };
+
+        let want = vec!["examples/day1/option.txt: no option \"top\"".to_string()];
+        assert_eq!(check(&fixture), want);
+    }
+}
@@ -0,0 +1,572 @@
+use std::{collections::HashSet, fmt::Display, ops::RangeInclusive, str::FromStr};
+
+pub const DEFAULT_SEED: u64 = 0;
+pub const DEFAULT_SIZE: usize = 10;
+
+/// A small SplitMix64 generator, so generated inputs only depend on the seed.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `0..bound`. `bound` must not be zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let span = range.end() - range.start();
+        match span.checked_add(1) {
+            Some(bound) => range.start() + self.below(bound),
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+}
+
+/// How hard a generated input is, in whatever way matters for the day:
+/// bigger numbers, denser grids or more edge cases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    fn pick<T>(self, easy: T, normal: T, hard: T) -> T {
+        match self {
+            Difficulty::Easy => easy,
+            Difficulty::Normal => normal,
+            Difficulty::Hard => hard,
+        }
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "easy" => Ok(Difficulty::Easy),
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(format!(
+                "difficulty must be easy, normal or hard, got: {text:?}"
+            )),
+        }
+    }
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = self.pick("easy", "normal", "hard");
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub seed: u64,
+    /// Roughly how many lines, rows or items the input has.
+    pub size: usize,
+    pub difficulty: Difficulty,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            seed: DEFAULT_SEED,
+            size: DEFAULT_SIZE,
+            difficulty: Difficulty::Normal,
+        }
+    }
+}
+
+type Generator = fn(&mut Rng, usize, Difficulty) -> String;
+
+pub fn generator(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => rotations,
+        2 => id_ranges,
+        3 => banks,
+        4 => paper_grid,
+        5 => inventory,
+        6 => worksheet,
+        7 => manifold,
+        8 => junction_boxes,
+        9 => polygon,
+        10 => machines,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+/// Generates an input for `day` that its parser accepts, or `None` if the
+/// day has no generator.
+pub fn generate(day: u8, params: &Params) -> Option<String> {
+    let generator = generator(day)?;
+    let mut rng = Rng::new(params.seed);
+    Some(generator(&mut rng, params.size.max(1), params.difficulty))
+}
+
+/// Puzzle parameters to ask a generated input with, as `(name, value)`, for
+/// days whose defaults only suit full-size inputs. Day 8 makes 1000
+/// connections, which joins every box of an input this small, so make
+/// fewer connections than there are pairs.
+pub fn options(day: u8, params: &Params) -> Vec<(String, String)> {
+    match day {
+        8 => {
+            let boxes = params.size.max(1) as u64;
+            let pairs = boxes * (boxes - 1) / 2;
+            let mut rng = Rng::new(!params.seed);
+            let connections = rng.range(1..=pairs.saturating_sub(1).max(1));
+            vec![("connections".to_string(), connections.to_string())]
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Day 1: dial turns. Harder inputs turn further and land on zero more often.
+pub fn rotations(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+    let max = difficulty.pick(99, 999, 99_999);
+    let zero_chance = difficulty.pick(0.05, 0.2, 0.4);
+    let mut position = 50;
+    let mut out = String::new();
+    for _ in 0..size {
+        let left = rng.chance(0.5);
+        let distance = if rng.chance(zero_chance) {
+            let to_zero = if left {
+                position
+            } else {
+                (100 - position) % 100
+            };
+            to_zero + 100 * rng.range(0..=max / 100)
+        } else {
+            rng.range(1..=max)
+        };
+        let distance = distance.max(1);
+        position = if left {
+            (position as i64 - distance as i64).rem_euclid(100) as u64
+        } else {
+            (position + distance) % 100
+        };
+        out += &format!("{}{distance}\n", if left { 'L' } else { 'R' });
+    }
+    out
+}
+
+/// Day 2: one line of comma-separated ID ranges.
+pub fn id_ranges(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+    let max_digits = difficulty.pick(4, 8, 12);
+    let max_width = difficulty.pick(100, 10_000, 100_000);
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = rng.range(1..=max_digits) as u32;
+            let start = rng.range(10_u64.pow(digits - 1)..=10_u64.pow(digits) - 1);
+            let end = start + rng.range(0..=max_width);
+            format!("{start}-{end}")
+        })
+        .collect();
+    ranges.join(",") + "\n"
+}
+
+/// Day 3: banks of battery digits. Harder banks are longer and repeat their
+/// largest digits.
+pub fn banks(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+    let len = difficulty.pick(15, 50, 100);
+    let mut out = String::new();
+    for _ in 0..size {
+        let low = difficulty.pick(1, 1, rng.range(1..=8));
+        let bank: String = (0..len)
+            .map(|_| char::from_digit(rng.range(low..=9) as u32, 10).unwrap())
+            .collect();
+        out += &bank;
+        out.push('\n');
+    }
+    out
+}
+
+/// Day 4: a square grid of paper rolls. Harder grids are denser.
+pub fn paper_grid(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+    let density = difficulty.pick(0.4, 0.6, 0.8);
+    let mut out = String::new();
+    for _ in 0..size {
+        let row: String = (0..size)
+            .map(|_| if rng.chance(density) { '@' } else { '.' })
+            .collect();
+        out += &row;
+        out.push('\n');
+    }
+    out
+}
+
+/// Day 5: fresh ranges then ingredient IDs. Harder inputs use larger IDs and
+/// more ranges that overlap, nest or touch.
+pub fn inventory(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+    let max = difficulty.pick(1_000, 1_000_000, 1_000_000_000_000_000);
+    let max_width = max / 50;
+    let overlap_chance = difficulty.pick(0.1, 0.3, 0.6);
+
+    let mut ranges: Vec<(u64, u64)> = Vec::with_capacity(size);
+    for _ in 0..size {
+        let (start, end) = match ranges.last() {
+            Some(&(start, end)) if rng.chance(overlap_chance) => {
+                let start = rng.range(start..=end + 1);
+                (start, start + rng.range(0..=max_width))
+            }
+            _ => {
+                let start = rng.range(0..=max);
+                (start, start + rng.range(0..=max_width))
+            }
+        };
+        ranges.push((start, end));
+    }
+
+    let mut out = String::new();
+    for (start, end) in &ranges {
+        out += &format!("{start}-{end}\n");
+    }
+    out.push('\n');
+    for _ in 0..size {
+        let id = if rng.chance(0.5) {
+            let (start, end) = ranges[rng.index(ranges.len())];
+            rng.range(start..=end)
+        } else {
+            rng.range(0..=max + max_width)
+        };
+        out += &format!("{id}\n");
+    }
+    out
+}
+
+/// Day 6: a worksheet of column-aligned problems. Harder worksheets have
+/// more rows and wider numbers.
+pub fn worksheet(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+    let rows = difficulty.pick(2, 3, 4);
+    let max_width = difficulty.pick(2, 3, 4);
+
+    let mut lines = vec![String::new(); rows + 1];
+    for problem in 0..size {
+        let width = rng.range(1..=max_width) as usize;
+        // Usually one number fills the whole width. When none does, a column
+        // at the edge of the problem holds no digits at all.
+        let full_row = rng.chance(0.8).then(|| rng.index(rows));
+        let left_aligned = rng.chance(0.5);
+        for (row, line) in lines.iter_mut().take(rows).enumerate() {
+            let digits = if full_row == Some(row) {
+                width
+            } else {
+                rng.range(1..=width as u64) as usize
+            };
+            let number = rng.range(10_u64.pow(digits as u32 - 1)..=10_u64.pow(digits as u32) - 1);
+            if problem > 0 {
+                line.push(' ');
+            }
+            if left_aligned {
+                *line += &format!("{number:<width$}");
+            } else {
+                *line += &format!("{number:>width$}");
+            }
+        }
+
+        let operator = if rng.chance(0.5) { '+' } else { '*' };
+        if problem > 0 {
+            lines[rows].push(' ');
+        }
+        lines[rows] += &format!("{operator:<width$}");
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Day 7: a tachyon manifold with splitters on every other row. Harder
+/// manifolds have more splitters.
+pub fn manifold(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+    let density = difficulty.pick(0.2, 0.4, 0.6);
+    let width = size * 2 + 1;
+    let start = size;
+
+    let mut out = ".".repeat(start) + "S" + &".".repeat(start) + "\n";
+    for row in 1..=size * 2 {
+        let line: String = (0..width)
+            .map(|column| {
+                let splitter = row % 2 == 0
+                    && column > 0
+                    && column < width - 1
+                    && (column + row / 2 + 1) % 2 == start % 2
+                    && rng.chance(density);
+                if splitter { '^' } else { '.' }
+            })
+            .collect();
+        out += &line;
+        out.push('\n');
+    }
+    out
+}
+
+/// Day 8: distinct junction box coordinates. Harder inputs cover a wider
+/// space on a coarse lattice, so many pairs share a distance, centred on the
+/// origin so that coordinates can be negative.
+pub fn junction_boxes(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+    let max = difficulty.pick(1_000, 100_000, 100_000);
+    let step = difficulty.pick(1, 1, 1_000);
+    let cells = max / step;
+    // Make sure there is room for `size` distinct boxes.
+    let cells = cells.max((size as f64).cbrt().ceil() as u64 * 2);
+    let offset = difficulty.pick(0, 0, (cells / 2 * step) as i64);
+
+    let mut seen = HashSet::with_capacity(size);
+    let mut out = String::new();
+    while seen.len() < size {
+        let mut coord = || (rng.range(0..=cells) * step) as i64 - offset;
+        let coord = (coord(), coord(), coord());
+        if seen.insert(coord) {
+            out += &format!("{},{},{}\n", coord.0, coord.1, coord.2);
+        }
+    }
+    out
+}
+
+/// Day 9: the red tiles of a simple rectilinear polygon with roughly `size`
+/// corners, listed in order around the outline. The polygon is x-monotone,
+/// built from columns that each overlap their neighbours, and may be
+/// transposed, reversed or started at any corner. Harder polygons span
+/// larger coordinates.
+pub fn polygon(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+    let max_step = difficulty.pick(4, 100, 5_000);
+    let columns = (size / 4).max(1);
+
+    let mut xs = vec![rng.range(0..=max_step)];
+    for _ in 0..columns {
+        let last = *xs.last().unwrap();
+        xs.push(last + rng.range(1..=max_step));
+    }
+
+    // Each column spans bottom..top, overlapping the previous column by at
+    // least one tile and never repeating a height, so no corners collapse.
+    // Tops are at least 2, so there is always a bottom below the last top
+    // other than the last bottom.
+    let height = max_step * 4;
+    let bottom = rng.range(0..=height);
+    let mut spans = vec![(bottom, bottom + rng.range(2..=height.max(2)))];
+    while spans.len() < columns {
+        let (last_bottom, last_top) = *spans.last().unwrap();
+        let bottom = loop {
+            let bottom = rng.range(0..=last_top - 1);
+            if bottom != last_bottom {
+                break bottom;
+            }
+        };
+        let low = (bottom.max(last_bottom) + 1).max(2);
+        let top = loop {
+            let top = rng.range(low..=low + height);
+            if top != last_top {
+                break top;
+            }
+        };
+        spans.push((bottom, top));
+    }
+
+    let mut corners = Vec::with_capacity(columns * 4);
+    for (index, &(bottom, _)) in spans.iter().enumerate() {
+        corners.push((xs[index], bottom));
+        corners.push((xs[index + 1], bottom));
+    }
+    for (index, &(_, top)) in spans.iter().enumerate().rev() {
+        corners.push((xs[index + 1], top));
+        corners.push((xs[index], top));
+    }
+
+    if rng.chance(0.5) {
+        corners.iter_mut().for_each(|(x, y)| (*x, *y) = (*y, *x));
+    }
+    if rng.chance(0.5) {
+        corners.reverse();
+    }
+    let first = rng.index(corners.len());
+    corners.rotate_left(first);
+
+    corners.iter().map(|(x, y)| format!("{x},{y}\n")).collect()
+}
+
+/// Day 10: machines whose target lights can be reached by pressing some of
+/// their buttons. Joltages are sums of button presses, so they can be met
+/// too. Harder machines have more lights and buttons.
+pub fn machines(rng: &mut Rng, size: usize, difficulty: Difficulty) -> String {
+    let max_lights = difficulty.pick(5, 10, 16);
+    let max_presses = difficulty.pick(5, 20, 100);
+
+    let mut out = String::new();
+    for _ in 0..size {
+        let lights = rng.range(3..=max_lights) as usize;
+        let button_count = rng.range(2..=lights as u64 + 3) as usize;
+        let buttons: Vec<Vec<usize>> = (0..button_count)
+            .map(|_| {
+                let wired: Vec<usize> = (0..lights).filter(|_| rng.chance(0.4)).collect();
+                if wired.is_empty() {
+                    vec![rng.index(lights)]
+                } else {
+                    wired
+                }
+            })
+            .collect();
+
+        // Pressing no buttons can be the answer, so targets may be all off.
+        let mut target = vec![false; lights];
+        for button in buttons.iter().filter(|_| rng.chance(0.5)) {
+            for &light in button {
+                target[light] ^= true;
+            }
+        }
+
+        let mut joltages = vec![0; lights];
+        for button in &buttons {
+            let presses = rng.range(0..=max_presses);
+            for &light in button {
+                joltages[light] += presses;
+            }
+        }
+
+        let diagram: String = target
+            .iter()
+            .map(|&on| if on { '#' } else { '.' })
+            .collect();
+        let buttons: Vec<String> = buttons
+            .iter()
+            .map(|button| format!("({})", join(button)))
+            .collect();
+        out += &format!(
+            "[{diagram}] {} {{{}}}\n",
+            buttons.join(" "),
+            join(&joltages)
+        );
+    }
+    out
+}
+
+fn join<T: Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let mut first = Rng::new(42);
+        let mut second = Rng::new(42);
+        let got: Vec<u64> = (0..5).map(|_| first.next_u64()).collect();
+        let want: Vec<u64> = (0..5).map(|_| second.next_u64()).collect();
+
+        assert_eq!(got, want);
+        assert_ne!(Rng::new(43).next_u64(), got[0]);
+    }
+
+    #[test]
+    fn test_rng_range() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            let value = rng.range(3..=5);
+            assert!((3..=5).contains(&value), "{value}");
+        }
+        assert_eq!(rng.range(9..=9), 9);
+        rng.range(0..=u64::MAX);
+    }
+
+    #[test]
+    fn test_generate_is_reproducible() {
+        let params = Params {
+            seed: 2025,
+            ..Params::default()
+        };
+        for day in 1..=10 {
+            let want = generate(day, &params).unwrap();
+            assert_eq!(generate(day, &params), Some(want.clone()));
+
+            let other = Params {
+                seed: 2026,
+                ..params
+            };
+            assert_ne!(generate(day, &other), Some(want), "day {day}");
+        }
+        assert_eq!(generate(11, &params), None);
+    }
+
+    #[test]
+    fn test_generated_inputs_parse_and_solve() {
+        for puzzle in registry::DAYS {
+            let day = puzzle.day();
+            for difficulty in Difficulty::ALL {
+                for (seed, size) in [(0, 1), (1, 4), (2, 12), (3, 25)] {
+                    let params = Params {
+                        seed,
+                        size,
+                        difficulty,
+                    };
+                    let input = generate(day, &params).unwrap();
+                    let parsed = puzzle
+                        .parse(&input)
+                        .unwrap_or_else(|err| panic!("{params:?} for day {day}: {err}\n{input}"));
+                    puzzle.solve(parsed.as_ref(), 1);
+                    puzzle.solve(parsed.as_ref(), 2);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_polygon_is_rectilinear() {
+        for seed in 0..50 {
+            let text = polygon(&mut Rng::new(seed), 20, Difficulty::Normal);
+            let corners: Vec<(u64, u64)> = text
+                .lines()
+                .map(|line| {
+                    let (x, y) = line.split_once(',').unwrap();
+                    (x.parse().unwrap(), y.parse().unwrap())
+                })
+                .collect();
+            let unique: HashSet<_> = corners.iter().collect();
+
+            assert_eq!(unique.len(), corners.len(), "{text}");
+            for (index, corner) in corners.iter().enumerate() {
+                let next = corners[(index + 1) % corners.len()];
+                assert!(
+                    (corner.0 == next.0) != (corner.1 == next.1),
+                    "{corner:?} -> {next:?} in\n{text}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_difficulty() {
+        assert_eq!("hard".parse(), Ok(Difficulty::Hard));
+        assert!("extreme".parse::<Difficulty>().is_err());
+    }
+}
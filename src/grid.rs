@@ -0,0 +1,323 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    error::ParseError,
+    point::{P2, Point},
+};
+
+/// A position in a grid, with `(0, 0)` in the top-left corner.
+pub type Pos = P2<usize>;
+
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A dense rectangular grid, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses one cell per character with `cell`, which returns `None` for
+    /// characters that do not belong in the grid. Every line must be as wide
+    /// as the first.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            if line.chars().count() != width {
+                let expected = format!("a row of width {width}");
+                return Err(ParseError::new(day, y + 1, 1, line, expected));
+            }
+            for (x, char) in line.chars().enumerate() {
+                let value = cell(char).ok_or_else(|| {
+                    ParseError::new(day, y + 1, x + 1, char.to_string(), expected)
+                })?;
+                cells.push(value);
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.inside(&Point([self.width, self.height]))
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.offset(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        let offset = self.offset(pos);
+        Some(&mut self.cells[offset])
+    }
+
+    /// Replaces the cell at `pos`, returning the old value, or `None` if
+    /// `pos` is outside the grid.
+    pub fn set(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point([x, y])))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The positions above, left, right and below `pos` that are inside the
+    /// grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + use<T> {
+        self.neighbours(pos, &OFFSETS_4)
+    }
+
+    /// Like [`Grid::neighbours4`], including the diagonals.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + use<T> {
+        self.neighbours(pos, &OFFSETS_8)
+    }
+
+    fn neighbours(
+        &self,
+        Point([x, y]): Pos,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + use<T> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx).filter(|&x| x < width)?;
+            let y = y.checked_add_signed(dy).filter(|&y| y < height)?;
+            Some(Point([x, y]))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` rejects a chunk size of zero, so empty rows are built by hand.
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| (0..self.height).map(move |y| &self.cells[y * self.width + x]))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| (0..self.height).map(move |y| &self.cells[y * self.width + x]))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |Point([x, y])| Point([y, x]))
+    }
+
+    /// Turns the grid a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(height, self.width, |Point([x, y])| {
+            Point([y, height - 1 - x])
+        })
+    }
+
+    /// Turns the grid a quarter turn anticlockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, width, |Point([x, y])| {
+            Point([width - 1 - y, x])
+        })
+    }
+
+    /// A `width` by `height` grid whose cell at `pos` is this grid's cell at
+    /// `source(pos)`.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point([x, y])))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    fn offset(&self, Point([x, y]): Pos) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside a {width}x{height} grid"))
+    }
+}
+
+/// Writes each row on its own line, cells side by side.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(input: &str) -> Grid<char> {
+        Grid::parse(0, input, "any character", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = chars("abc\ndef\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point([2, 1])), Some(&'f'));
+        assert_eq!(grid.get(Point([3, 1])), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let digit = |char: char| char.to_digit(10);
+        assert_eq!(
+            Grid::parse(3, "12\n3x", "a digit", digit),
+            Err(ParseError::new(3, 2, 2, "x", "a digit"))
+        );
+        assert_eq!(
+            Grid::parse(3, "12\n345", "a digit", digit),
+            Err(ParseError::new(3, 2, 1, "345", "a row of width 2"))
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut grid = Grid::new(2, 2, 0);
+        assert_eq!(grid.set(Point([1, 0]), 5), Some(0));
+        assert_eq!(grid.set(Point([0, 2]), 5), None);
+        grid[Point([0, 1])] += 3;
+        assert_eq!(grid.to_string(), "05\n30\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, ());
+        let corner: Vec<Pos> = grid.neighbours4(Point([0, 0])).collect();
+        assert_eq!(corner, [Point([1, 0]), Point([0, 1])]);
+        assert_eq!(grid.neighbours4(Point([1, 1])).count(), 4);
+        assert_eq!(grid.neighbours8(Point([1, 1])).count(), 8);
+        let edge: Vec<Pos> = grid.neighbours8(Point([2, 1])).collect();
+        assert_eq!(
+            edge,
+            [(1, 0), (2, 0), (1, 1), (1, 2), (2, 2)].map(|(x, y)| Point([x, y]))
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = chars("abc\ndef");
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        let column: String = grid.column(1).unwrap().collect();
+        assert_eq!(column, "be");
+        let columns: Vec<String> = grid.columns().map(Iterator::collect).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = chars("abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+}
@@ -0,0 +1,342 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+pub fn get(url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+    request("GET", url, headers, None)
+}
+
+pub fn post_form(
+    url: &str,
+    headers: &[(&str, &str)],
+    form: &[(&str, &str)],
+) -> io::Result<Response> {
+    let body = form
+        .iter()
+        .map(|(key, value)| format!("{}={}", url_encode(key), url_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&");
+    let mut headers = headers.to_vec();
+    headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+    request("POST", url, &headers, Some(&body))
+}
+
+/// Plain `http://` URLs are spoken directly over a socket. The standard
+/// library has no TLS, so `https://` requests go through `curl`.
+fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> io::Result<Response> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        request_plain(method, rest, headers, body)
+    } else if url.starts_with("https://") {
+        request_curl(method, url, headers, body)
+    } else {
+        Err(invalid(format!("unsupported URL: {url:?}")))
+    }
+}
+
+fn request_plain(
+    method: &str,
+    host_and_path: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> io::Result<Response> {
+    let (host, path) = match host_and_path.find('/') {
+        Some(index) => host_and_path.split_at(index),
+        None => (host_and_path, "/"),
+    };
+    let address = if host.contains(':') {
+        host.to_string()
+    } else {
+        format!("{host}:80")
+    };
+
+    let mut stream = TcpStream::connect(address)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut request = format!("{method} {path} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n");
+    for (name, value) in headers {
+        request += &format!("{name}: {value}\r\n");
+    }
+    let body = body.unwrap_or("");
+    if method != "GET" {
+        request += &format!("Content-Length: {}\r\n", body.len());
+    }
+    request += "\r\n";
+    request += body;
+    stream.write_all(request.as_bytes())?;
+
+    read_response(BufReader::new(stream))
+}
+
+fn read_response(mut reader: impl BufRead) -> io::Result<Response> {
+    let mut status_line = String::new();
+    reader.read_line(&mut status_line)?;
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(|| invalid(format!("bad status line: {status_line:?}")))?;
+
+    let mut chunked = false;
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let value = value.trim();
+            match name.trim().to_ascii_lowercase().as_str() {
+                "transfer-encoding" => chunked = value.eq_ignore_ascii_case("chunked"),
+                "content-length" => content_length = value.parse::<usize>().ok(),
+                _ => {}
+            }
+        }
+    }
+
+    let mut body = Vec::new();
+    if chunked {
+        loop {
+            let mut size = String::new();
+            reader.read_line(&mut size)?;
+            let size = usize::from_str_radix(size.trim().split(';').next().unwrap_or(""), 16)
+                .map_err(|_| invalid(format!("bad chunk size: {size:?}")))?;
+            if size == 0 {
+                break;
+            }
+            let start = body.len();
+            body.resize(start + size, 0);
+            reader.read_exact(&mut body[start..])?;
+            reader.read_line(&mut String::new())?;
+        }
+    } else if let Some(length) = content_length {
+        body.resize(length, 0);
+        reader.read_exact(&mut body)?;
+    } else {
+        reader.read_to_end(&mut body)?;
+    }
+
+    let body = String::from_utf8(body).map_err(|_| invalid("response is not UTF-8".to_string()))?;
+    Ok(Response { status, body })
+}
+
+/// Headers and body go to curl as a config file on stdin, since anything on
+/// its command line, such as the session cookie, is visible to every local
+/// user.
+fn request_curl(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> io::Result<Response> {
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--request", method])
+        .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+        .args(["--write-out", "\n%{http_code}"])
+        .args(["--config", "-"])
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()?;
+    let config = curl_config(headers, body);
+    // Dropping stdin once written closes it, so curl stops reading.
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(config.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "curl exited with {}",
+            output.status
+        )));
+    }
+
+    let output = String::from_utf8(output.stdout)
+        .map_err(|_| invalid("response is not UTF-8".to_string()))?;
+    let (body, status) = output
+        .rsplit_once('\n')
+        .ok_or_else(|| invalid("curl did not report a status".to_string()))?;
+    let status = status
+        .trim()
+        .parse()
+        .map_err(|_| invalid(format!("bad status from curl: {status:?}")))?;
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+/// A curl config file setting each header and the body.
+fn curl_config(headers: &[(&str, &str)], body: Option<&str>) -> String {
+    let quote = |text: &str| {
+        let mut quoted = String::from('"');
+        for char in text.chars() {
+            match char {
+                '"' | '\\' => {
+                    quoted.push('\\');
+                    quoted.push(char);
+                }
+                '\n' => quoted.push_str("\\n"),
+                '\r' => quoted.push_str("\\r"),
+                '\t' => quoted.push_str("\\t"),
+                char => quoted.push(char),
+            }
+        }
+        quoted.push('"');
+        quoted
+    };
+    let mut config = String::new();
+    for (name, value) in headers {
+        config += &format!("header = {}\n", quote(&format!("{name}: {value}")));
+    }
+    if let Some(body) = body {
+        config += &format!("data-binary = {}\n", quote(body));
+    }
+    config
+}
+
+fn url_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves `response` to a single connection on a free local port.
+    /// Returns the base URL and a handle yielding the raw request.
+    pub(crate) fn serve(status: u16, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        );
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.strip_prefix("Content-Length: ") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request += &line;
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request += &String::from_utf8(body).unwrap();
+
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let (base_url, server) = stub::serve(200, "L68\nR30\n");
+        let got = get(&format!("{base_url}/input"), &[("Cookie", "session=abc")]).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(
+            got,
+            Response {
+                status: 200,
+                body: "L68\nR30\n".to_string()
+            }
+        );
+        assert!(request.starts_with("GET /input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn test_post_form() {
+        let (base_url, server) = stub::serve(404, "nope");
+        let got = post_form(&base_url, &[], &[("level", "1"), ("answer", "a b&c")]).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(got.status, 404);
+        assert!(request.starts_with("POST / HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=1&answer=a%20b%26c"));
+    }
+
+    #[test]
+    fn test_curl_config() {
+        let got = curl_config(&[("Cookie", "session=a\"b")], Some("level=1&answer=x\\y"));
+        assert_eq!(
+            got,
+            "header = \"Cookie: session=a\\\"b\"\ndata-binary = \"level=1&answer=x\\\\y\"\n"
+        );
+    }
+
+    #[test]
+    fn test_request_curl() {
+        let (base_url, server) = stub::serve(200, "ok");
+        let headers = [("Cookie", "session=abc")];
+        let got = request_curl("POST", &base_url, &headers, Some("level=1")).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(got.body, "ok");
+        assert!(request.contains("Cookie: session=abc\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=1"));
+    }
+
+    #[test]
+    fn test_read_chunked_response() {
+        let raw = "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nL68\n\r\n3\r\nR5\n\r\n0\r\n\r\n";
+        let got = read_response(raw.as_bytes()).unwrap();
+        assert_eq!(got.body, "L68\nR5\n");
+    }
+
+    #[test]
+    fn test_unsupported_url() {
+        assert!(get("ftp://example.com", &[]).is_err());
+    }
+}
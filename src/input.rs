@@ -0,0 +1,126 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Component, Path, PathBuf},
+};
+
+/// Environment variable that overrides the directory holding `dayN.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+const DEFAULT_INPUT_DIR: &str = "input";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `dayN.txt` in the input directory.
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Reads `-` as stdin and anything else as a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        match self {
+            Source::Default => fs::read_to_string(default_path(day)),
+            Source::File(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Where the input for `day` comes from, for use in messages.
+    pub fn name(&self, day: u8) -> String {
+        match self {
+            Source::Default => default_path(day).display().to_string(),
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => "<stdin>".to_string(),
+        }
+    }
+
+    /// Names the input for keying saved answers and timings, with `.` and
+    /// `..` resolved so that `input/day1.txt` and `./input/day1.txt` are the
+    /// same input.
+    pub fn key(&self, day: u8) -> String {
+        match self {
+            Source::Default => normalise(&default_path(day)),
+            Source::File(path) => normalise(path),
+            Source::Stdin => "<stdin>".to_string(),
+        }
+    }
+}
+
+/// Resolves `.` and `..` without touching the file system.
+fn normalise(path: &Path) -> String {
+    let mut parts: Vec<Component> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if matches!(parts.last(), Some(Component::Normal(_))) => {
+                parts.pop();
+            }
+            component => parts.push(component),
+        }
+    }
+    parts.iter().collect::<PathBuf>().display().to_string()
+}
+
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .filter(|dir| !dir.is_empty())
+        .map_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR), PathBuf::from)
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    day_path(&input_dir(), day)
+}
+
+pub fn day_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{day}.txt"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("inputs/alice/day3.txt"),
+            Source::File(PathBuf::from("inputs/alice/day3.txt"))
+        );
+    }
+
+    #[test]
+    fn test_day_path() {
+        let got = day_path(Path::new("inputs/bob"), 7);
+        assert_eq!(got, PathBuf::from("inputs/bob/day7.txt"));
+    }
+
+    #[test]
+    fn test_read_file() {
+        let source = Source::File(PathBuf::from("input/day1.txt"));
+        let got = source.read(1).unwrap();
+        assert!(got.starts_with('L') || got.starts_with('R'));
+        assert_eq!(source.name(1), "input/day1.txt");
+    }
+
+    #[test]
+    fn test_key() {
+        let key = |path: &str| Source::from_arg(path).key(1);
+        assert_eq!(key("input/day1.txt"), "input/day1.txt");
+        assert_eq!(key("./input/day1.txt"), "input/day1.txt");
+        assert_eq!(key("input/../input/./day1.txt"), "input/day1.txt");
+        assert_eq!(key("../day1.txt"), "../day1.txt");
+        assert_eq!(key("-"), "<stdin>");
+    }
+}
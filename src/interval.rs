@@ -0,0 +1,229 @@
+use std::ops::{Add, RangeInclusive, Sub};
+
+/// An integer type whose values can be counted and stepped through.
+pub trait Discrete: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_next(self) -> Option<Self>;
+    fn checked_prev(self) -> Option<Self>;
+}
+
+macro_rules! discrete {
+    ($($t:ty),*) => {
+        $(impl Discrete for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn checked_prev(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+        })*
+    };
+}
+
+discrete!(i32, i64, isize, u32, u64, usize);
+
+/// A set of integers stored as sorted, disjoint inclusive ranges. Ranges
+/// that overlap or touch are merged as they are inserted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Ranges ending just before `start`, or starting just after `end`,
+        // touch the new one and are merged into it.
+        let first = self
+            .ranges
+            .partition_point(|&(_, high)| high.checked_next().is_some_and(|next| next < start));
+        let last = self
+            .ranges
+            .partition_point(|&(low, _)| end.checked_next().is_none_or(|next| low <= next));
+        let merged = match self.ranges.get(first..last) {
+            Some([(low, _), .., (_, high)]) | Some([(low, high)]) => {
+                (start.min(*low), end.max(*high))
+            }
+            _ => (start, end),
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Takes every value in `range` out of the set.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self.ranges.partition_point(|&(_, high)| high < start);
+        let last = self.ranges.partition_point(|&(low, _)| low <= end);
+        if first >= last {
+            return;
+        }
+        let (low, high) = (self.ranges[first].0, self.ranges[last - 1].1);
+        let left = start.checked_prev().filter(|_| low < start);
+        let right = end.checked_next().filter(|_| high > end);
+        let kept = [left.map(|prev| (low, prev)), right.map(|next| (next, high))];
+        self.ranges.splice(first..last, kept.into_iter().flatten());
+    }
+
+    /// Whether `value` is in the set, by binary search.
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|&(low, _)| low <= value);
+        index > 0 && self.ranges[index - 1].1 >= value
+    }
+
+    /// How many values are in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |sum, &(low, high)| sum + (high - low) + T::ONE)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The disjoint ranges making up the set, lowest first.
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> {
+        self.ranges.iter().map(|&(low, high)| low..=high)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.ranges() {
+            union.insert(range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut mine, mut theirs) = (self.ranges.iter(), other.ranges.iter());
+        let (mut a, mut b) = (mine.next(), theirs.next());
+        let mut ranges = Vec::new();
+        while let (Some(&(a_low, a_high)), Some(&(b_low, b_high))) = (a, b) {
+            let (low, high) = (a_low.max(b_low), a_high.min(b_high));
+            if low <= high {
+                ranges.push((low, high));
+            }
+            if a_high < b_high {
+                a = mine.next();
+            } else {
+                b = theirs.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values in this set that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.ranges() {
+            difference.remove(range);
+        }
+        difference
+    }
+
+    /// The values within `bounds` that are not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        Self::from_iter([bounds]).difference(self)
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges(set: &IntervalSet<i64>) -> Vec<RangeInclusive<i64>> {
+        set.ranges().collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        assert_eq!(ranges(&set(&[6..=8, 0..=2, 1..=4])), [0..=4, 6..=8]);
+        assert_eq!(ranges(&set(&[0..=4, 5..=5, 7..=9])), [0..=5, 7..=9]);
+        assert_eq!(ranges(&set(&[1..=2, 5..=6, 9..=9, 0..=7])), [0..=7, 9..=9]);
+        assert_eq!(ranges(&set(&[RangeInclusive::new(3, 1)])), []);
+    }
+
+    #[test]
+    fn test_insert_at_limits() {
+        let set: IntervalSet<u64> = [u64::MAX - 1..=u64::MAX, 0..=0, 1..=2]
+            .into_iter()
+            .collect();
+        let got: Vec<_> = set.ranges().collect();
+        assert_eq!(got, [0..=2, u64::MAX - 1..=u64::MAX]);
+        assert!(set.contains(u64::MAX));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut got = set(&[0..=10, 20..=30]);
+        got.remove(5..=22);
+        assert_eq!(ranges(&got), [0..=4, 23..=30]);
+        got.remove(0..=4);
+        got.remove(30..=40);
+        assert_eq!(ranges(&got), [23..=29]);
+        got.remove(25..=25);
+        assert_eq!(ranges(&got), [23..=24, 26..=29]);
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = set(&[3..=5, 10..=14, 16..=20, 12..=18]);
+        let fresh: Vec<i64> = (0..25).filter(|&id| set.contains(id)).collect();
+        let want: Vec<i64> = (3..=5).chain(10..=20).collect();
+        assert_eq!(fresh, want);
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(set(&[3..=5, 10..=14, 16..=20, 12..=18]).len(), 14);
+        assert_eq!(set(&[]).len(), 0);
+        assert!(set(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[0..=5, 10..=15]);
+        let b = set(&[4..=11, 20..=21]);
+        assert_eq!(ranges(&a.union(&b)), [0..=15, 20..=21]);
+        assert_eq!(ranges(&a.intersection(&b)), [4..=5, 10..=11]);
+        assert_eq!(ranges(&a.difference(&b)), [0..=3, 12..=15]);
+        assert_eq!(ranges(&a.complement(-5..=12)), [-5..=-1, 6..=9]);
+    }
+}
@@ -0,0 +1,394 @@
+use std::fmt::{self, Display, Write};
+
+/// A minimal JSON value, enough for the files this crate reads and writes.
+/// Objects keep their keys in insertion order so written files diff cleanly.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(num) => Some(*num),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Replaces the value under `key`, or appends it if the key is new.
+    /// Does nothing unless `self` is an object.
+    pub fn insert(&mut self, key: &str, value: Value) {
+        if let Value::Object(entries) = self {
+            match entries.iter_mut().find(|(k, _)| k == key) {
+                Some((_, existing)) => *existing = value,
+                None => entries.push((key.to_string(), value)),
+            }
+        }
+    }
+
+    /// Renders with two-space indentation and a trailing newline.
+    pub fn to_pretty_string(&self) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, 0);
+        out.push('\n');
+        out
+    }
+
+    fn write_pretty(&self, out: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth + 1);
+        match self {
+            Value::Array(values) if !values.is_empty() => {
+                out.push_str("[\n");
+                for (index, value) in values.iter().enumerate() {
+                    out.push_str(&indent);
+                    value.write_pretty(out, depth + 1);
+                    out.push_str(if index + 1 < values.len() {
+                        ",\n"
+                    } else {
+                        "\n"
+                    });
+                }
+                out.push_str(&"  ".repeat(depth));
+                out.push(']');
+            }
+            Value::Object(entries) if !entries.is_empty() => {
+                out.push_str("{\n");
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    out.push_str(&indent);
+                    write_string(out, key);
+                    out.push_str(": ");
+                    value.write_pretty(out, depth + 1);
+                    out.push_str(if index + 1 < entries.len() {
+                        ",\n"
+                    } else {
+                        "\n"
+                    });
+                }
+                out.push_str(&"  ".repeat(depth));
+                out.push('}');
+            }
+            value => {
+                write!(out, "{value}").expect("writing to a String cannot fail");
+            }
+        }
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Number(value)
+    }
+}
+
+impl From<usize> for Value {
+    fn from(value: usize) -> Self {
+        Value::Number(value as f64)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(bool) => write!(f, "{bool}"),
+            Value::Number(num) if num.is_finite() => write!(f, "{num}"),
+            Value::Number(_) => write!(f, "null"),
+            Value::String(string) => {
+                let mut out = String::new();
+                write_string(&mut out, string);
+                f.write_str(&out)
+            }
+            Value::Array(values) => {
+                f.write_char('[')?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_char(']')
+            }
+            Value::Object(entries) => {
+                f.write_char('{')?;
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    if index > 0 {
+                        f.write_char(',')?;
+                    }
+                    let mut out = String::new();
+                    write_string(&mut out, key);
+                    write!(f, "{out}:{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_string(out: &mut String, string: &str) {
+    out.push('"');
+    for char in string.chars() {
+        match char {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            char if (char as u32) < 0x20 => {
+                write!(out, "\\u{:04x}", char as u32).expect("writing to a String cannot fail")
+            }
+            char => out.push(char),
+        }
+    }
+    out.push('"');
+}
+
+/// Parses a complete JSON document. The error is the byte offset where
+/// parsing stopped.
+pub fn parse(input: &str) -> Result<Value, usize> {
+    let mut parser = Parser {
+        bytes: input.as_bytes(),
+        pos: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.pos == parser.bytes.len() {
+        Ok(value)
+    } else {
+        Err(parser.pos)
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\n' | b'\r' | b'\t') = self.bytes.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), usize> {
+        if self.bytes.get(self.pos) == Some(&byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.pos)
+        }
+    }
+
+    fn literal(&mut self, text: &str, value: Value) -> Result<Value, usize> {
+        if self.bytes[self.pos..].starts_with(text.as_bytes()) {
+            self.pos += text.len();
+            Ok(value)
+        } else {
+            Err(self.pos)
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, usize> {
+        self.skip_whitespace();
+        match self.bytes.get(self.pos) {
+            Some(b'n') => self.literal("null", Value::Null),
+            Some(b't') => self.literal("true", Value::Bool(true)),
+            Some(b'f') => self.literal("false", Value::Bool(false)),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b'[') => self.array(),
+            Some(b'{') => self.object(),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => Err(self.pos),
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, usize> {
+        let start = self.pos;
+        while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.bytes.get(self.pos) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()
+            .and_then(|text| text.parse().ok())
+            .map(Value::Number)
+            .ok_or(start)
+    }
+
+    fn string(&mut self) -> Result<String, usize> {
+        self.expect(b'"')?;
+        let mut out = Vec::new();
+        loop {
+            let Some(&byte) = self.bytes.get(self.pos) else {
+                return Err(self.pos);
+            };
+            self.pos += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = match self.bytes.get(self.pos) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'u') => {
+                            let hex = self.bytes.get(self.pos + 1..self.pos + 5).ok_or(self.pos)?;
+                            let code = std::str::from_utf8(hex)
+                                .ok()
+                                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                                .and_then(char::from_u32)
+                                .ok_or(self.pos)?;
+                            self.pos += 4;
+                            code
+                        }
+                        _ => return Err(self.pos),
+                    };
+                    self.pos += 1;
+                    let mut buf = [0; 4];
+                    out.extend_from_slice(escaped.encode_utf8(&mut buf).as_bytes());
+                }
+                byte => out.push(byte),
+            }
+        }
+        String::from_utf8(out).map_err(|_| self.pos)
+    }
+
+    fn array(&mut self) -> Result<Value, usize> {
+        self.expect(b'[')?;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.expect(b']').is_ok() {
+            return Ok(Value::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            if self.expect(b',').is_err() {
+                self.expect(b']')?;
+                return Ok(Value::Array(values));
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, usize> {
+        self.expect(b'{')?;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if self.expect(b'}').is_ok() {
+            return Ok(Value::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            if self.expect(b',').is_err() {
+                self.expect(b'}')?;
+                return Ok(Value::Object(entries));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let value = Value::Object(vec![
+            ("name".to_string(), Value::from("day \"8\"\n")),
+            (
+                "sizes".to_string(),
+                Value::Array(vec![5.into(), 4.into(), 2.into()]),
+            ),
+            ("ratio".to_string(), Value::from(0.25)),
+            ("empty".to_string(), Value::Object(Vec::new())),
+            (
+                "flags".to_string(),
+                Value::Array(vec![Value::Bool(true), Value::Null]),
+            ),
+        ]);
+
+        assert_eq!(parse(&value.to_string()), Ok(value.clone()));
+        assert_eq!(parse(&value.to_pretty_string()), Ok(value));
+    }
+
+    #[test]
+    fn test_pretty() {
+        let value = Value::Object(vec![(
+            "day1".to_string(),
+            Value::Array(vec![1.into(), 2.into()]),
+        )]);
+        assert_eq!(
+            value.to_pretty_string(),
+            "{\n  \"day1\": [\n    1,\n    2\n  ]\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_escapes_and_numbers() {
+        let got = parse(r#"{"a": "xA\t", "b": -1.5e3}"#).unwrap();
+        assert_eq!(got.get("a").and_then(Value::as_str), Some("xA\t"));
+        assert_eq!(got.get("b").and_then(Value::as_f64), Some(-1500.0));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("{\"a\": }"), Err(6));
+        assert_eq!(parse("[1, 2"), Err(5));
+        assert_eq!(parse("[1] x"), Err(4));
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut value = Value::Object(Vec::new());
+        value.insert("a", 1.into());
+        value.insert("b", 2.into());
+        value.insert("a", 3.into());
+        assert_eq!(value.to_string(), r#"{"a":3,"b":2}"#);
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+use crate::error::ParseError;
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod clustering;
+pub mod config;
+pub mod day_1;
+pub mod day_10;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+pub mod differential;
+pub mod error;
+pub mod fixtures;
+pub mod generate;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod interval;
+pub mod json;
+pub mod point;
+pub mod registry;
+pub mod runner;
+pub mod shrink;
+pub mod spatial;
+pub mod submissions;
+pub mod union_find;
+
+/// A single day's puzzle: parse the input once, then answer each part from it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Self::Answer1;
+
+    /// Returns `None` until the second part has been solved.
+    fn part_two(_input: &Self::Input) -> Option<Self::Answer2> {
+        None
+    }
+
+    /// A slow but obviously correct answer to the first part, read straight
+    /// from a well-formed input. Used to cross-check [`Solution::part_one`].
+    /// `options` are the `(name, value)` pairs the solver's input was
+    /// configured with, so that both answer the same question.
+    fn naive_part_one(_input: &str, _options: &[(String, String)]) -> Option<Self::Answer1> {
+        None
+    }
+
+    /// Like [`Solution::naive_part_one`], for the second part.
+    fn naive_part_two(_input: &str, _options: &[(String, String)]) -> Option<Self::Answer2> {
+        None
+    }
+
+    /// Sets a named parameter on parsed input, such as how many connections
+    /// day 8 makes, so the same questions can be asked of other datasets.
+    fn configure(_input: &mut Self::Input, name: &str, _value: &str) -> Result<(), String> {
+        Err(format!("no option {name:?}"))
+    }
+}
@@ -0,0 +1,351 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, Mul, Sub},
+    str::FromStr,
+};
+
+use crate::error::ParseError;
+
+/// A number that can be used as a point's coordinate.
+pub trait Coordinate:
+    Copy + Default + PartialOrd + FromStr + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// How far apart two coordinates are, which never underflows.
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    /// The coordinate as a float, for estimates that need not be exact.
+    fn to_f64(self) -> f64;
+
+    /// A total order, so that even float coordinates can be sorted.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                self.cmp(other)
+            }
+        })*
+    };
+}
+
+coordinate!(i32, i64, isize, u32, u64, usize);
+
+impl Coordinate for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f64::total_cmp(self, other)
+    }
+}
+
+/// How the distance between two points is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Metric {
+    Manhattan,
+    Chebyshev,
+    /// Measured squared, which orders points the same way and stays exact
+    /// for integers.
+    #[default]
+    Euclidean,
+}
+
+impl Metric {
+    pub fn distance<const N: usize, T: Coordinate>(self, a: &Point<N, T>, b: &Point<N, T>) -> T {
+        match self {
+            Metric::Manhattan => a.manhattan(b),
+            Metric::Chebyshev => a.chebyshev(b),
+            Metric::Euclidean => a.squared_euclidean(b),
+        }
+    }
+
+    /// The distance spanned by the given distances along each axis, as a
+    /// float.
+    pub fn estimate(self, axes: impl IntoIterator<Item = f64>) -> f64 {
+        let axes = axes.into_iter();
+        match self {
+            Metric::Manhattan => axes.sum(),
+            Metric::Chebyshev => axes.fold(0.0, f64::max),
+            Metric::Euclidean => axes.map(|axis| axis * axis).sum(),
+        }
+    }
+
+    /// The distance of points `radius` apart in a straight line, in the
+    /// units [`Metric::distance`] measures in.
+    pub fn scale(self, radius: f64) -> f64 {
+        match self {
+            Metric::Manhattan | Metric::Chebyshev => radius,
+            Metric::Euclidean => radius * radius,
+        }
+    }
+
+    /// The straight-line length a distance from [`Metric::distance`] stands
+    /// for, undoing the squaring of Euclidean distances.
+    pub fn length(self, distance: f64) -> f64 {
+        match self {
+            Metric::Manhattan | Metric::Chebyshev => distance,
+            Metric::Euclidean => distance.sqrt(),
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "manhattan" => Ok(Metric::Manhattan),
+            "chebyshev" => Ok(Metric::Chebyshev),
+            "euclidean" => Ok(Metric::Euclidean),
+            _ => Err(format!(
+                "metric must be manhattan, chebyshev or euclidean, got: {text:?}"
+            )),
+        }
+    }
+}
+
+impl Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Metric::Manhattan => "manhattan",
+            Metric::Chebyshev => "chebyshev",
+            Metric::Euclidean => "euclidean",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A point, or a vector, in `N` dimensions. Points order by their
+/// coordinates in turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize, T>(pub [T; N]);
+
+pub type P2<T> = Point<2, T>;
+pub type P3<T> = Point<3, T>;
+
+impl<T: Copy> P2<T> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+}
+
+impl<T: Copy> P3<T> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+}
+
+impl<const N: usize, T: Coordinate> Point<N, T> {
+    /// The sum of the distances along each axis.
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.distances(other)
+            .fold(T::default(), |sum, distance| sum + distance)
+    }
+
+    /// The largest distance along any one axis.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        let larger = |max: T, distance: T| if distance > max { distance } else { max };
+        self.distances(other).fold(T::default(), larger)
+    }
+
+    /// The straight-line distance squared, which stays exact for integers.
+    pub fn squared_euclidean(&self, other: &Self) -> T {
+        self.distances(other)
+            .fold(T::default(), |sum, distance| sum + distance * distance)
+    }
+
+    /// Orders by each coordinate in turn, like `Ord` does, but for floats
+    /// too.
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .map(|(a, b)| a.total_cmp(b))
+            .find(|order| order.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    fn distances(&self, other: &Self) -> impl Iterator<Item = T> {
+        self.0.iter().zip(&other.0).map(|(&a, &b)| a.distance(b))
+    }
+
+    /// Whether every coordinate lies in `0..size` along its axis.
+    pub fn inside(&self, size: &Self) -> bool {
+        self.0
+            .iter()
+            .zip(&size.0)
+            .all(|(&coord, &size)| coord >= T::default() && coord < size)
+    }
+
+    /// Parses `N` comma-separated numbers. Errors point at the offending
+    /// number, or at the whole line when the count is wrong. Floats must be
+    /// finite.
+    pub fn parse(day: u8, line: &str, expected: &str) -> Result<Self, ParseError> {
+        let nums: Vec<&str> = line.split(',').collect();
+        if nums.len() != N {
+            return Err(ParseError::in_line(day, line, line, expected));
+        }
+        let mut coords = [T::default(); N];
+        for (coord, num) in coords.iter_mut().zip(nums) {
+            *coord = num
+                .parse()
+                .ok()
+                .filter(|coord: &T| coord.to_f64().is_finite())
+                .ok_or_else(|| ParseError::in_line(day, line, num, "a number"))?;
+        }
+        Ok(Self(coords))
+    }
+
+    fn zip_with(self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+        let mut coords = self.0;
+        for (coord, &other) in coords.iter_mut().zip(&other.0) {
+            *coord = f(*coord, other);
+        }
+        Self(coords)
+    }
+}
+
+impl<const N: usize, T: Coordinate> Add for Point<N, T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<const N: usize, T: Coordinate> Sub for Point<N, T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+/// Scales every coordinate.
+impl<const N: usize, T: Coordinate> Mul<T> for Point<N, T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self(self.0.map(|coord| coord * rhs))
+    }
+}
+
+/// Writes the coordinates comma-separated, the way inputs list them.
+impl<const N: usize, T: Display> Display for Point<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, coord) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{coord}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point([1, -2, 3]);
+        let b = Point([4, 5, -6]);
+        assert_eq!(a + b, Point([5, 3, -3]));
+        assert_eq!(a - b, Point([-3, -7, 9]));
+        assert_eq!(a * 2, Point([2, -4, 6]));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a: P3<usize> = Point([162, 817, 812]);
+        let b = Point([425, 690, 689]);
+        assert_eq!(a.manhattan(&b), 263 + 127 + 123);
+        assert_eq!(b.manhattan(&a), a.manhattan(&b));
+        assert_eq!(a.chebyshev(&b), 263);
+        assert_eq!(a.squared_euclidean(&b), 263 * 263 + 127 * 127 + 123 * 123);
+        assert_eq!(Metric::Manhattan.distance(&a, &b), a.manhattan(&b));
+        assert_eq!(Metric::Chebyshev.distance(&a, &b), 263);
+        assert_eq!(Metric::Euclidean.distance(&a, &b), a.squared_euclidean(&b));
+    }
+
+    #[test]
+    fn test_metric_estimate() {
+        assert_eq!(Metric::Manhattan.estimate([3.0, 4.0]), 7.0);
+        assert_eq!(Metric::Chebyshev.estimate([3.0, 4.0]), 4.0);
+        assert_eq!(
+            Metric::Euclidean.estimate([3.0, 4.0]),
+            Metric::Euclidean.scale(5.0)
+        );
+        assert_eq!("chebyshev".parse(), Ok(Metric::Chebyshev));
+        assert!("taxicab".parse::<Metric>().is_err());
+    }
+
+    #[test]
+    fn test_inside() {
+        let size = Point([3, 2]);
+        assert!(Point([2, 1]).inside(&size));
+        assert!(!Point([3, 1]).inside(&size));
+        assert!(!Point([-1, 0]).inside(&size));
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut points = vec![Point([2, 1]), Point([1, 5]), Point([1, 2])];
+        points.sort();
+        assert_eq!(points, [Point([1, 2]), Point([1, 5]), Point([2, 1])]);
+        assert_eq!(
+            Point([1.0, -0.5]).total_cmp(&Point([1.0, 0.5])),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let expected = "two numbers like '7,1'";
+        assert_eq!(P2::parse(9, "7,1", expected), Ok(Point([7usize, 1])));
+        assert_eq!(
+            P2::<usize>::parse(9, "7,x", expected),
+            Err(ParseError::new(9, 1, 3, "x", "a number"))
+        );
+        assert_eq!(
+            P2::<usize>::parse(9, "7,1,4", expected),
+            Err(ParseError::new(9, 1, 1, "7,1,4", expected))
+        );
+        assert_eq!(Point([7, -1, 0]).to_string(), "7,-1,0");
+        assert_eq!(
+            P3::parse(8, "-1.5,2,0.25", expected),
+            Ok(Point([-1.5, 2.0, 0.25]))
+        );
+        assert_eq!(
+            P2::<f64>::parse(9, "7,NaN", expected),
+            Err(ParseError::new(9, 1, 3, "NaN", "a number"))
+        );
+    }
+}
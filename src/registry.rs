@@ -0,0 +1,131 @@
+use std::any::Any;
+
+use crate::{
+    Solution, day_1::Day1, day_2::Day2, day_3::Day3, day_4::Day4, day_5::Day5, day_6::Day6,
+    day_7::Day7, day_8::Day8, day_9::Day9, day_10::Day10, error::ParseError,
+};
+
+/// Object-safe view of a [`Solution`], so every day can live in one table.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Sets a named parameter on input produced by [`Puzzle::parse`].
+    fn configure(&self, input: &mut dyn Any, name: &str, value: &str) -> Result<(), String>;
+
+    /// Answers `part` from input produced by [`Puzzle::parse`]. Returns `None`
+    /// when the day has no solver for that part.
+    fn solve(&self, input: &dyn Any, part: u8) -> Option<String>;
+
+    /// Answers `part` with the day's naive reference, if it has one, asking
+    /// it the question that `options` set with [`Puzzle::configure`].
+    fn solve_naive(&self, input: &str, options: &[(String, String)], part: u8) -> Option<String>;
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn configure(&self, input: &mut dyn Any, name: &str, value: &str) -> Result<(), String> {
+        let input = input
+            .downcast_mut::<S::Input>()
+            .expect("input should come from the same day's parse");
+        S::configure(input, name, value)
+    }
+
+    fn solve(&self, input: &dyn Any, part: u8) -> Option<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input should come from the same day's parse");
+        match part {
+            1 => Some(S::part_one(input).to_string()),
+            2 => S::part_two(input).map(|answer| answer.to_string()),
+            _ => None,
+        }
+    }
+
+    fn solve_naive(&self, input: &str, options: &[(String, String)], part: u8) -> Option<String> {
+        match part {
+            1 => S::naive_part_one(input, options).map(|answer| answer.to_string()),
+            2 => S::naive_part_two(input, options).map(|answer| answer.to_string()),
+            _ => None,
+        }
+    }
+}
+
+pub const DAYS: [&dyn Puzzle; 10] = [
+    &Day1, &Day2, &Day3, &Day4, &Day5, &Day6, &Day7, &Day8, &Day9, &Day10,
+];
+
+pub fn find(number: u8) -> Option<&'static dyn Puzzle> {
+    DAYS.iter().copied().find(|puzzle| puzzle.day() == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_every_day() {
+        for number in 1..=10 {
+            assert_eq!(find(number).map(|puzzle| puzzle.day()), Some(number));
+        }
+        assert!(find(11).is_none());
+    }
+
+    #[test]
+    fn test_solve_parts() {
+        let puzzle = find(1).unwrap();
+        let input = puzzle
+            .parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82")
+            .unwrap();
+        assert_eq!(puzzle.solve(input.as_ref(), 1), Some("3".to_string()));
+        assert_eq!(puzzle.solve(input.as_ref(), 2), Some("6".to_string()));
+        assert_eq!(puzzle.solve(input.as_ref(), 3), None);
+    }
+
+    #[test]
+    fn test_configure() {
+        let puzzle = find(8).unwrap();
+        let mut input = puzzle.parse("0,0,0\n1,0,0\n5,0,0").unwrap();
+        assert_eq!(puzzle.solve(input.as_ref(), 1), Some("3".to_string()));
+        puzzle
+            .configure(input.as_mut(), "connections", "1")
+            .unwrap();
+        assert_eq!(puzzle.solve(input.as_ref(), 1), Some("2".to_string()));
+        assert!(
+            puzzle
+                .configure(input.as_mut(), "connections", "x")
+                .is_err()
+        );
+        assert!(puzzle.configure(input.as_mut(), "colour", "red").is_err());
+
+        let mut input = find(1).unwrap().parse("L5").unwrap();
+        assert!(
+            find(1)
+                .unwrap()
+                .configure(input.as_mut(), "top", "3")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_missing_part_two() {
+        let puzzle = find(10).unwrap();
+        let input = puzzle
+            .parse("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}")
+            .unwrap();
+        assert_eq!(puzzle.solve(input.as_ref(), 1), Some("2".to_string()));
+        assert_eq!(puzzle.solve(input.as_ref(), 2), None);
+    }
+}
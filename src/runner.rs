@@ -0,0 +1,1192 @@
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{Answers, DEFAULT_ANSWERS, Verdict},
+    bench::{self, DEFAULT_BASELINE, DEFAULT_ITERATIONS, DEFAULT_THRESHOLD},
+    client::{Client, Reply},
+    config::{Config, DEFAULT_CONFIG},
+    differential::{self, DEFAULT_CASES, DEFAULT_MAX_SIZE},
+    error::ParseError,
+    fixtures::{self, EXAMPLES_DIR},
+    generate::{self, Difficulty, Params},
+    input::{self, INPUT_DIR_VAR, Source},
+    registry::{self, DAYS, Puzzle},
+    shrink::{self, Failure},
+    submissions::{DEFAULT_SUBMISSIONS, Feedback, Submissions},
+};
+
+const DEFAULT_SHRUNK_NAME: &str = "shrunk";
+
+const USAGE: &str =
+    "usage: aoc run <day>... [--part 1|2] [--input <path>|-] [--set <name>=<value>]...
+               [--answers <path>] [--record]
+       aoc run --all [--part 1|2] [--answers <path>] [--record]
+       aoc bench <day> [--part 1|2] [--input <path>|-] [--iterations <n>]
+                 [--baseline <path>] [--threshold <percent>] [--save]
+       aoc fetch <day> [--config <path>] [--force]
+       aoc submit <day> <part> [--answer <value>] [--input <path>|-]
+                  [--config <path>] [--answers <path>] [--submissions <path>]
+       aoc generate <day> [--seed <n>] [--size <n>] [--difficulty easy|normal|hard]
+       aoc diff <day>... | --all [--part 1|2] [--cases <n>] [--seed <n>] [--size <n>]
+                [--difficulty easy|normal|hard] [--set <name>=<value>]... [--shrink] [--force]
+       aoc shrink <day> [--part 1|2] [--input <path>|-] [--set <name>=<value>]...
+                  [--name <name>] [--force]
+
+diff checks solvers against naive references on generated inputs up to --size
+shrink cuts a failing input down and saves it under examples/dayN/<name>.txt,
+replacing an existing example only with --force
+inputs default to dayN.txt in $AOC_INPUT_DIR, or ./input when it is unset
+--set changes a puzzle parameter, like --set connections=10 for day 8
+results are checked against answers.toml unless --set is given; --record
+stores them as confirmed
+fetch and submit read the session cookie from aoc.toml or $AOC_SESSION
+submit refuses answers that submissions.toml already rules out";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        days: Vec<u8>,
+        part: Option<u8>,
+        source: Source,
+        /// Puzzle parameters as `(name, value)`, set in order after parsing.
+        options: Vec<(String, String)>,
+        answers: PathBuf,
+        record: bool,
+    },
+    Bench {
+        day: u8,
+        part: Option<u8>,
+        source: Source,
+        iterations: usize,
+        baseline: PathBuf,
+        threshold: f64,
+        save: bool,
+    },
+    Fetch {
+        day: u8,
+        config: PathBuf,
+        force: bool,
+    },
+    Submit {
+        day: u8,
+        part: u8,
+        /// Submitted as is instead of solving the input.
+        answer: Option<String>,
+        source: Source,
+        config: PathBuf,
+        answers: PathBuf,
+        submissions: PathBuf,
+    },
+    Generate {
+        day: u8,
+        params: Params,
+    },
+    /// `params.size` is the largest size tried.
+    Diff {
+        days: Vec<u8>,
+        part: Option<u8>,
+        cases: usize,
+        params: Params,
+        options: Vec<(String, String)>,
+        shrink: bool,
+        /// Whether shrunk examples may replace existing ones.
+        force: bool,
+    },
+    Shrink {
+        day: u8,
+        part: Option<u8>,
+        source: Source,
+        options: Vec<(String, String)>,
+        name: String,
+        force: bool,
+    },
+}
+
+/// Entry point for the `aoc` binary. Returns the process exit code.
+pub fn main(args: impl IntoIterator<Item = String>) -> i32 {
+    let ok = match parse_args(args) {
+        Ok(Command::Run {
+            days,
+            part,
+            source,
+            options,
+            answers,
+            record,
+        }) => run(&days, part, &source, &options, &answers, record),
+        Ok(Command::Bench {
+            day,
+            part,
+            source,
+            iterations,
+            baseline,
+            threshold,
+            save,
+        }) => run_bench(day, part, &source, iterations, &baseline, threshold, save),
+        Ok(Command::Fetch { day, config, force }) => fetch(day, &config, force),
+        Ok(Command::Submit {
+            day,
+            part,
+            answer,
+            source,
+            config,
+            answers,
+            submissions,
+        }) => submit(day, part, answer, &source, &config, &answers, &submissions),
+        Ok(Command::Generate { day, params }) => {
+            let input = generate::generate(day, &params).expect("every day has a generator");
+            print!("{input}");
+            true
+        }
+        Ok(Command::Diff {
+            days,
+            part,
+            cases,
+            params,
+            options,
+            shrink,
+            force,
+        }) => diff(&days, part, cases, &params, &options, shrink, force),
+        Ok(Command::Shrink {
+            day,
+            part,
+            source,
+            options,
+            name,
+            force,
+        }) => shrink_input(day, part, &source, &options, &name, force),
+        Err(err) => {
+            eprintln!("error: {err}\n{USAGE}");
+            return 2;
+        }
+    };
+
+    if ok { 0 } else { 1 }
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let command = args.next().ok_or("missing command")?;
+    if !matches!(
+        command.as_str(),
+        "run" | "bench" | "fetch" | "submit" | "generate" | "diff" | "shrink"
+    ) {
+        return Err(format!("unknown command: {command:?}"));
+    }
+
+    let mut days: Option<Vec<u8>> = None;
+    let mut part = None;
+    let mut source = Source::Default;
+    let mut iterations = DEFAULT_ITERATIONS;
+    let mut baseline = PathBuf::from(DEFAULT_BASELINE);
+    let mut threshold = DEFAULT_THRESHOLD;
+    let mut save = false;
+    let mut options = Vec::new();
+    let mut answers = PathBuf::from(DEFAULT_ANSWERS);
+    let mut record = false;
+    let mut config = PathBuf::from(DEFAULT_CONFIG);
+    let mut force = false;
+    let mut answer = None;
+    let mut submissions = PathBuf::from(DEFAULT_SUBMISSIONS);
+    let mut params = Params::default();
+    let mut cases = DEFAULT_CASES;
+    let mut shrink = false;
+    let mut name = DEFAULT_SHRUNK_NAME.to_string();
+    if command == "diff" {
+        params.size = DEFAULT_MAX_SIZE;
+        params.difficulty = Difficulty::Easy;
+    }
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{arg} requires a value"));
+        match (command.as_str(), arg.as_str()) {
+            ("run" | "diff", "--all") => {
+                days = Some(DAYS.iter().map(|puzzle| puzzle.day()).collect())
+            }
+            ("run" | "bench" | "diff" | "shrink", "--part") => part = Some(parse_part(&value()?)?),
+            ("run" | "bench" | "submit" | "shrink", "--input") => {
+                source = Source::from_arg(&value()?)
+            }
+            ("run" | "diff" | "shrink", "--set") => {
+                let value = value()?;
+                let (name, value) = value
+                    .split_once('=')
+                    .ok_or(format!("--set expects <name>=<value>, got: {value:?}"))?;
+                options.push((name.to_string(), value.to_string()));
+            }
+            ("run" | "submit", "--answers") => answers = PathBuf::from(value()?),
+            ("run", "--record") => record = true,
+            ("bench", "--iterations") => {
+                let value = value()?;
+                iterations = value
+                    .parse()
+                    .ok()
+                    .filter(|&iterations| iterations > 0)
+                    .ok_or(format!(
+                        "iterations must be a positive number, got: {value:?}"
+                    ))?;
+            }
+            ("bench", "--baseline") => baseline = PathBuf::from(value()?),
+            ("bench", "--threshold") => {
+                let value = value()?;
+                threshold = value
+                    .parse()
+                    .ok()
+                    .filter(|threshold: &f64| *threshold >= 0.0)
+                    .ok_or(format!("threshold must be a percentage, got: {value:?}"))?;
+            }
+            ("bench", "--save") => save = true,
+            ("diff", "--cases") => {
+                let value = value()?;
+                cases = value
+                    .parse()
+                    .ok()
+                    .filter(|&cases| cases > 0)
+                    .ok_or(format!("cases must be a positive number, got: {value:?}"))?;
+            }
+            ("diff", "--shrink") => shrink = true,
+            ("shrink", "--name") => name = value()?,
+            ("fetch" | "submit", "--config") => config = PathBuf::from(value()?),
+            ("fetch" | "diff" | "shrink", "--force") => force = true,
+            ("submit", "--answer") => answer = Some(value()?),
+            ("submit", "--submissions") => submissions = PathBuf::from(value()?),
+            ("generate" | "diff", "--seed") => {
+                let value = value()?;
+                params.seed = value
+                    .parse()
+                    .map_err(|_| format!("seed must be a number, got: {value:?}"))?;
+            }
+            ("generate" | "diff", "--size") => {
+                let value = value()?;
+                params.size = value
+                    .parse()
+                    .ok()
+                    .filter(|&size| size > 0)
+                    .ok_or(format!("size must be a positive number, got: {value:?}"))?;
+            }
+            ("generate" | "diff", "--difficulty") => {
+                params.difficulty = value()?.parse::<Difficulty>()?
+            }
+            (_, flag) if flag.starts_with("--") => {
+                return Err(format!("unknown option for {command}: {flag:?}"));
+            }
+            ("submit", part_arg) if days.is_some() => match part {
+                None => part = Some(parse_part(part_arg)?),
+                Some(_) => return Err(format!("unexpected argument: {part_arg:?}")),
+            },
+            (_, day) => {
+                let day = parse_day(day)?;
+                match &mut days {
+                    None => days = Some(vec![day]),
+                    Some(days) if matches!(command.as_str(), "run" | "diff") => {
+                        if !days.contains(&day) {
+                            days.push(day);
+                        }
+                    }
+                    Some(_) => {
+                        return Err(format!("{command} takes a single day, got another: {day}"));
+                    }
+                }
+            }
+        }
+    }
+
+    let days = days.ok_or(if matches!(command.as_str(), "run" | "diff") {
+        "expected a day or --all"
+    } else {
+        "expected a day"
+    })?;
+    if source != Source::Default && days.len() != 1 {
+        return Err(format!(
+            "--input needs a single day; use {INPUT_DIR_VAR} to point --all at another directory"
+        ));
+    }
+    if !options.is_empty() && days.len() != 1 {
+        return Err("--set needs a single day".to_string());
+    }
+    if !options.is_empty() && record {
+        return Err(
+            "--record only stores answers to the puzzle as asked, without --set".to_string(),
+        );
+    }
+
+    match command.as_str() {
+        "fetch" => {
+            return Ok(Command::Fetch {
+                day: days[0],
+                config,
+                force,
+            });
+        }
+        "submit" => {
+            return Ok(Command::Submit {
+                day: days[0],
+                part: part.ok_or("expected a part after the day")?,
+                answer,
+                source,
+                config,
+                answers,
+                submissions,
+            });
+        }
+        "generate" => {
+            return Ok(Command::Generate {
+                day: days[0],
+                params,
+            });
+        }
+        "diff" => {
+            return Ok(Command::Diff {
+                days,
+                part,
+                cases,
+                params,
+                options,
+                shrink,
+                force,
+            });
+        }
+        "shrink" => {
+            return Ok(Command::Shrink {
+                day: days[0],
+                part,
+                source,
+                options,
+                name,
+                force,
+            });
+        }
+        "bench" => {
+            return Ok(Command::Bench {
+                day: days[0],
+                part,
+                source,
+                iterations,
+                baseline,
+                threshold,
+                save,
+            });
+        }
+        _ => {}
+    }
+    Ok(Command::Run {
+        days,
+        part,
+        source,
+        options,
+        answers,
+        record,
+    })
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    value
+        .parse::<u8>()
+        .ok()
+        .filter(|&number| registry::find(number).is_some())
+        .ok_or_else(|| format!("no solver for day: {value:?}"))
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("part must be 1 or 2, got: {value:?}")),
+    }
+}
+
+/// Runs every requested day and part, printing one line per result along
+/// with how it compares to the confirmed answers. Answers found with
+/// `options` set are not compared, since they answer a different question.
+/// With several days, a requested part that some day lacks is skipped.
+/// Returns false if any of them failed or disagreed with a confirmed answer.
+pub fn run(
+    days: &[u8],
+    part: Option<u8>,
+    source: &Source,
+    options: &[(String, String)],
+    answers_path: &Path,
+    record: bool,
+) -> bool {
+    let mut answers = match Answers::load(answers_path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("could not load answers: {err}");
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    for &number in days {
+        let puzzle = registry::find(number).expect("days are validated while parsing");
+        ok &= run_day(
+            puzzle,
+            part,
+            days.len() == 1,
+            source,
+            options,
+            &mut answers,
+            record,
+        );
+    }
+
+    if record {
+        match answers.save(answers_path) {
+            Ok(()) => println!("recorded answers in {}", answers_path.display()),
+            Err(err) => {
+                eprintln!("could not save answers: {err}");
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+fn read_input(day: u8, source: &Source) -> Option<String> {
+    source
+        .read(day)
+        .map_err(|err| eprintln!("day {day}: could not read {}: {err}", source.name(day)))
+        .ok()
+}
+
+fn report_parse_error(day: u8, source: &Source, input: &str, err: &ParseError) {
+    let location = format!("{}:{}:{}", source.name(day), err.line, err.column);
+    eprintln!("{location}\n{}", err.diagnostic(input));
+}
+
+/// Runs one day. A requested part the day has no solver for only fails when
+/// the day is `alone`; running several days skips it.
+fn run_day(
+    puzzle: &dyn Puzzle,
+    part: Option<u8>,
+    alone: bool,
+    source: &Source,
+    options: &[(String, String)],
+    answers: &mut Answers,
+    record: bool,
+) -> bool {
+    let number = puzzle.day();
+    let name = source.key(number);
+    let Some(input) = read_input(number, source) else {
+        return false;
+    };
+
+    let mut parsed = match puzzle.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            report_parse_error(number, source, &input, &err);
+            return false;
+        }
+    };
+    for (option, value) in options {
+        if let Err(err) = puzzle.configure(parsed.as_mut(), option, value) {
+            eprintln!("day {number}: {err}");
+            return false;
+        }
+    }
+
+    // When no part is requested, days without a second part are not an error.
+    let (parts, explicit) = match part {
+        Some(part) => (vec![part], true),
+        None => (vec![1, 2], false),
+    };
+
+    let mut ok = true;
+    for part in parts {
+        match panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve(parsed.as_ref(), part))) {
+            Ok(Some(answer)) => {
+                let verdict = if options.is_empty() {
+                    answers.check(number, &name, part, &answer)
+                } else {
+                    Verdict::Unknown
+                };
+                println!("day {number} part {part}: {answer} {verdict}");
+                ok &= !matches!(verdict, Verdict::Fail { .. });
+                if record {
+                    answers.set(number, &name, part, &answer);
+                }
+            }
+            Ok(None) if explicit && alone => {
+                eprintln!("day {number} part {part}: no solver");
+                ok = false;
+            }
+            Ok(None) if explicit => println!("day {number} part {part}: no solver, skipped"),
+            Ok(None) => {}
+            Err(_) => {
+                eprintln!("day {number} part {part}: failed");
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+fn load_client(config_path: &Path) -> Option<Client> {
+    Config::load(config_path)
+        .map_err(|err| format!("could not load config: {err}"))
+        .and_then(|config| Client::new(&config).map_err(|err| err.to_string()))
+        .map_err(|err| eprintln!("{err}"))
+        .ok()
+}
+
+/// Downloads the input for `day` into the input directory. An existing
+/// file is only replaced when `force` is set.
+fn fetch(day: u8, config_path: &Path, force: bool) -> bool {
+    let path = input::default_path(day);
+    if path.exists() && !force {
+        eprintln!(
+            "{} already exists; pass --force to download it again",
+            path.display()
+        );
+        return false;
+    }
+    let Some(client) = load_client(config_path) else {
+        return false;
+    };
+
+    let input = match client.fetch_input(day) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("day {day}: {err}");
+            return false;
+        }
+    };
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&path, input));
+    match written {
+        Ok(()) => {
+            println!("day {day}: saved input to {}", path.display());
+            true
+        }
+        Err(err) => {
+            eprintln!("could not write {}: {err}", path.display());
+            false
+        }
+    }
+}
+
+/// Posts an answer for one part, solving `source` unless an answer was
+/// given. Answers ruled out by earlier feedback are never sent, and every
+/// reply with feedback is added to the submissions file. Correct answers are
+/// recorded as confirmed when they were solved rather than given. Returns
+/// true only when the site accepted the answer.
+fn submit(
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    source: &Source,
+    config_path: &Path,
+    answers_path: &Path,
+    submissions_path: &Path,
+) -> bool {
+    let Some(client) = load_client(config_path) else {
+        return false;
+    };
+    let mut submissions = match Submissions::load(submissions_path) {
+        Ok(submissions) => submissions,
+        Err(err) => {
+            eprintln!("could not load submissions: {err}");
+            return false;
+        }
+    };
+    let account = client.account();
+    // Only answers the solver found are worth recording once confirmed.
+    let solved = answer.is_none();
+    let Some(answer) = answer.or_else(|| solve(day, part, source)) else {
+        return false;
+    };
+    if let Err(refusal) = submissions.check(&account, day, part, &answer) {
+        eprintln!("day {day} part {part}: not submitting {answer}: {refusal}");
+        return false;
+    }
+
+    println!("day {day} part {part}: submitting {answer}");
+    let reply = match client.submit(day, part, &answer) {
+        Ok(reply) => reply,
+        Err(err) => {
+            eprintln!("day {day} part {part}: {err}");
+            return false;
+        }
+    };
+    println!("day {day} part {part}: {reply}");
+    if let Some(feedback) = Feedback::from_reply(&reply) {
+        submissions.record(&account, day, part, &answer, feedback);
+        if let Err(err) = submissions.save(submissions_path) {
+            eprintln!("could not save submissions: {err}");
+        }
+    }
+    if reply != Reply::Correct {
+        return false;
+    }
+    if !solved {
+        return true;
+    }
+
+    let recorded = Answers::load(answers_path).and_then(|mut answers| {
+        answers.set(day, &source.key(day), part, &answer);
+        answers.save(answers_path)
+    });
+    match recorded {
+        Ok(()) => println!("recorded answer in {}", answers_path.display()),
+        Err(err) => eprintln!("could not save answers: {err}"),
+    }
+    true
+}
+
+fn solve(day: u8, part: u8, source: &Source) -> Option<String> {
+    let puzzle = registry::find(day).expect("days are validated while parsing");
+    let input = read_input(day, source)?;
+    let parsed = puzzle
+        .parse(&input)
+        .map_err(|err| report_parse_error(day, source, &input, &err))
+        .ok()?;
+
+    match panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve(parsed.as_ref(), part))) {
+        Ok(Some(answer)) => Some(answer),
+        Ok(None) => {
+            eprintln!("day {day} part {part}: no solver");
+            None
+        }
+        Err(_) => {
+            eprintln!("day {day} part {part}: failed");
+            None
+        }
+    }
+}
+
+/// Cross-checks each day's solver against its naive reference, printing the
+/// smallest generated input they disagree on. Returns false on any mismatch.
+fn diff(
+    days: &[u8],
+    part: Option<u8>,
+    cases: usize,
+    params: &Params,
+    options: &[(String, String)],
+    shrink: bool,
+    force: bool,
+) -> bool {
+    let parts = part.map_or(vec![1, 2], |part| vec![part]);
+    // Panics are reported as mismatches, so keep their messages quiet.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut ok = true;
+    for &number in days {
+        let puzzle = registry::find(number).expect("days are validated while parsing");
+        let sample = generate::generate(number, params).expect("every day has a generator");
+        if !options_apply(puzzle, &sample, options) {
+            ok = false;
+            continue;
+        }
+        let mismatch = differential::check_day(
+            puzzle,
+            &parts,
+            options,
+            cases,
+            params.seed,
+            params.size,
+            params.difficulty,
+        );
+        let Some(mismatch) = mismatch else {
+            println!("day {number}: {cases} cases agree");
+            continue;
+        };
+
+        ok = false;
+        let answer = |answer: &Option<String>| answer.clone().unwrap_or("panicked".to_string());
+        let Params {
+            seed,
+            size,
+            difficulty,
+        } = mismatch.params;
+        let set: String = mismatch
+            .options
+            .iter()
+            .map(|(name, value)| format!(" --set {name}={value}"))
+            .collect();
+        println!(
+            "day {number} part {}: solver {} but naive {}{set} (aoc generate {number} --seed {seed} --size {size} --difficulty {difficulty})",
+            mismatch.part,
+            answer(&mismatch.fast),
+            answer(&mismatch.naive),
+        );
+        print!("{}", mismatch.input);
+        if shrink {
+            let name = format!("diff-part{}-seed{seed}", mismatch.part);
+            if let Some((input, failure)) =
+                shrink::shrink_failure(puzzle, &mismatch.input, &mismatch.options, mismatch.part)
+            {
+                save_shrunk(puzzle, &input, &mismatch.options, failure, &name, force);
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+    ok
+}
+
+/// Checks that every option can be set on `input`, which the cross-checks
+/// would otherwise take as a solver that has nothing to say.
+fn options_apply(puzzle: &dyn Puzzle, input: &str, options: &[(String, String)]) -> bool {
+    let Ok(mut parsed) = puzzle.parse(input) else {
+        return true;
+    };
+    for (option, value) in options {
+        if let Err(err) = puzzle.configure(parsed.as_mut(), option, value) {
+            eprintln!("day {}: {err}", puzzle.day());
+            return false;
+        }
+    }
+    true
+}
+
+/// Shrinks the first part that fails on an input and saves the result as an
+/// example. Returns false if nothing failed or the example was not saved.
+fn shrink_input(
+    day: u8,
+    part: Option<u8>,
+    source: &Source,
+    options: &[(String, String)],
+    name: &str,
+    force: bool,
+) -> bool {
+    let puzzle = registry::find(day).expect("days are validated while parsing");
+    let Some(input) = read_input(day, source) else {
+        return false;
+    };
+    if !options_apply(puzzle, &input, options) {
+        return false;
+    }
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let parts = part.map_or(vec![1, 2], |part| vec![part]);
+    let shrunk = parts
+        .iter()
+        .find_map(|&part| shrink::shrink_failure(puzzle, &input, options, part));
+    panic::set_hook(hook);
+
+    match shrunk {
+        Some((input, failure)) => save_shrunk(puzzle, &input, options, failure, name, force),
+        None => {
+            eprintln!("day {day}: {} does not fail", source.name(day));
+            false
+        }
+    }
+}
+
+/// Writes a shrunk input to the examples directory, along with `options`,
+/// expecting the naive answer when there is one. An existing example is only
+/// replaced when `force` is set.
+fn save_shrunk(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    options: &[(String, String)],
+    failure: Failure,
+    name: &str,
+    force: bool,
+) -> bool {
+    let day = puzzle.day();
+    let Failure { part, .. } = failure;
+    let mut expected = [None, None];
+    if !failure.naive_panicked {
+        expected[part as usize - 1] = panic::catch_unwind(AssertUnwindSafe(|| {
+            puzzle.solve_naive(input, options, part)
+        }))
+        .ok()
+        .flatten();
+    }
+
+    println!(
+        "day {day} part {part}: shrunk to {} lines",
+        input.lines().count()
+    );
+    match fixtures::write(
+        Path::new(EXAMPLES_DIR),
+        day,
+        name,
+        input,
+        options,
+        &expected,
+        force,
+    ) {
+        Ok(path) => {
+            println!("saved {}", path.display());
+            true
+        }
+        Err(err) => {
+            eprintln!("could not save example: {err}");
+            false
+        }
+    }
+}
+
+/// Times one day and compares it against the baseline file. The baseline is
+/// written when `save` is set or when it has no entry for the day yet.
+/// Returns false on errors or if any phase regressed beyond `threshold`.
+fn run_bench(
+    day: u8,
+    part: Option<u8>,
+    source: &Source,
+    iterations: usize,
+    baseline_path: &Path,
+    threshold: f64,
+    save: bool,
+) -> bool {
+    let puzzle = registry::find(day).expect("days are validated while parsing");
+    let Some(input) = read_input(day, source) else {
+        return false;
+    };
+    let baseline = match bench::load_baseline(baseline_path) {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("could not load baseline: {err}");
+            return false;
+        }
+    };
+
+    let parts = part.map_or(vec![1, 2], |part| vec![part]);
+    let report = match bench::bench_day(puzzle, &source.key(day), &input, &parts, iterations) {
+        Ok(report) => report,
+        Err(err) => {
+            report_parse_error(day, source, &input, &err);
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    let changes = report.compare(&baseline, threshold);
+    for ((name, stats), change) in report.phases.iter().zip(&changes) {
+        let mut line = format!(
+            "day {day} {name}: min {:.2?} median {:.2?} p95 {:.2?}",
+            stats.min, stats.median, stats.p95
+        );
+        if let Some(change) = change {
+            line += &format!(
+                " baseline {:.2?} ({:+.1}%)",
+                change.baseline, change.percent
+            );
+            if change.regressed {
+                line += " REGRESSED";
+                ok = false;
+            }
+        }
+        println!("{line}");
+    }
+    for part in &report.failed {
+        eprintln!("day {day} part {part}: failed");
+        ok = false;
+    }
+
+    if save || report.saved(&baseline).is_none() {
+        match bench::save_baseline(baseline_path, baseline, &report) {
+            Ok(()) => println!("saved baseline to {}", baseline_path.display()),
+            Err(err) => {
+                eprintln!("could not save baseline: {err}");
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args_single_day() {
+        let got = parse_args(args(&["run", "3"]));
+        assert_eq!(
+            got,
+            Ok(Command::Run {
+                days: vec![3],
+                part: None,
+                source: Source::Default,
+                options: Vec::new(),
+                answers: PathBuf::from(DEFAULT_ANSWERS),
+                record: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_part() {
+        let got = parse_args(args(&["run", "9", "--part", "2"]));
+        assert_eq!(
+            got,
+            Ok(Command::Run {
+                days: vec![9],
+                part: Some(2),
+                source: Source::Default,
+                options: Vec::new(),
+                answers: PathBuf::from(DEFAULT_ANSWERS),
+                record: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_all() {
+        let got = parse_args(args(&["run", "--all"]));
+        assert_eq!(
+            got,
+            Ok(Command::Run {
+                days: (1..=10).collect(),
+                part: None,
+                source: Source::Default,
+                options: Vec::new(),
+                answers: PathBuf::from(DEFAULT_ANSWERS),
+                record: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_several_days() {
+        let got = parse_args(args(&["run", "3", "8", "3", "--part", "1"]));
+        assert_eq!(
+            got,
+            Ok(Command::Run {
+                days: vec![3, 8],
+                part: Some(1),
+                source: Source::Default,
+                options: Vec::new(),
+                answers: PathBuf::from(DEFAULT_ANSWERS),
+                record: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_input() {
+        let got = parse_args(args(&["run", "4", "--input", "-"]));
+        assert_eq!(
+            got,
+            Ok(Command::Run {
+                days: vec![4],
+                part: None,
+                source: Source::Stdin,
+                options: Vec::new(),
+                answers: PathBuf::from(DEFAULT_ANSWERS),
+                record: false,
+            })
+        );
+
+        let got = parse_args(args(&["run", "--input", "inputs/day4.txt", "4"]));
+        assert_eq!(
+            got,
+            Ok(Command::Run {
+                days: vec![4],
+                part: None,
+                source: Source::File("inputs/day4.txt".into()),
+                options: Vec::new(),
+                answers: PathBuf::from(DEFAULT_ANSWERS),
+                record: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_record() {
+        let got = parse_args(args(&[
+            "run",
+            "--all",
+            "--answers",
+            "mine.toml",
+            "--record",
+        ]));
+        assert_eq!(
+            got,
+            Ok(Command::Run {
+                days: (1..=10).collect(),
+                part: None,
+                source: Source::Default,
+                options: Vec::new(),
+                answers: PathBuf::from("mine.toml"),
+                record: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_set() {
+        let got = parse_args(args(&[
+            "run",
+            "8",
+            "--set",
+            "connections=10",
+            "--set",
+            "top=2",
+        ]));
+        assert_eq!(
+            got,
+            Ok(Command::Run {
+                days: vec![8],
+                part: None,
+                source: Source::Default,
+                options: vec![
+                    ("connections".to_string(), "10".to_string()),
+                    ("top".to_string(), "2".to_string()),
+                ],
+                answers: PathBuf::from(DEFAULT_ANSWERS),
+                record: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_bench() {
+        let got = parse_args(args(&[
+            "bench",
+            "8",
+            "--iterations",
+            "5",
+            "--threshold",
+            "2.5",
+            "--save",
+        ]));
+        assert_eq!(
+            got,
+            Ok(Command::Bench {
+                day: 8,
+                part: None,
+                source: Source::Default,
+                iterations: 5,
+                baseline: PathBuf::from(DEFAULT_BASELINE),
+                threshold: 2.5,
+                save: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_fetch() {
+        let got = parse_args(args(&["fetch", "5", "--force"]));
+        assert_eq!(
+            got,
+            Ok(Command::Fetch {
+                day: 5,
+                config: PathBuf::from(DEFAULT_CONFIG),
+                force: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_submit() {
+        let got = parse_args(args(&[
+            "submit", "8", "2", "--answer", "42", "--config", "ci.toml",
+        ]));
+        assert_eq!(
+            got,
+            Ok(Command::Submit {
+                day: 8,
+                part: 2,
+                answer: Some("42".to_string()),
+                source: Source::Default,
+                config: PathBuf::from("ci.toml"),
+                answers: PathBuf::from(DEFAULT_ANSWERS),
+                submissions: PathBuf::from(DEFAULT_SUBMISSIONS),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_generate() {
+        let got = parse_args(args(&[
+            "generate",
+            "9",
+            "--seed",
+            "7",
+            "--difficulty",
+            "hard",
+        ]));
+        assert_eq!(
+            got,
+            Ok(Command::Generate {
+                day: 9,
+                params: Params {
+                    seed: 7,
+                    size: generate::DEFAULT_SIZE,
+                    difficulty: Difficulty::Hard,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_diff() {
+        let got = parse_args(args(&["diff", "--all", "--cases", "50", "--size", "4"]));
+        assert_eq!(
+            got,
+            Ok(Command::Diff {
+                days: (1..=10).collect(),
+                part: None,
+                cases: 50,
+                params: Params {
+                    seed: generate::DEFAULT_SEED,
+                    size: 4,
+                    difficulty: Difficulty::Easy,
+                },
+                options: Vec::new(),
+                shrink: false,
+                force: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_shrink() {
+        let got = parse_args(args(&[
+            "shrink", "8", "--input", "-", "--set", "top=2", "--name", "notch", "--force",
+        ]));
+        assert_eq!(
+            got,
+            Ok(Command::Shrink {
+                day: 8,
+                part: None,
+                source: Source::Stdin,
+                options: vec![("top".to_string(), "2".to_string())],
+                name: "notch".to_string(),
+                force: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse_args(args(&[])).is_err());
+        assert!(parse_args(args(&["walk", "1"])).is_err());
+        assert!(parse_args(args(&["run"])).is_err());
+        assert!(parse_args(args(&["run", "42"])).is_err());
+        assert!(parse_args(args(&["run", "1", "--part", "3"])).is_err());
+        assert!(parse_args(args(&["run", "1", "--part"])).is_err());
+        assert!(parse_args(args(&["run", "1", "--input"])).is_err());
+        assert!(parse_args(args(&["run", "--all", "--input", "-"])).is_err());
+        assert!(parse_args(args(&["run", "1", "--save"])).is_err());
+        assert!(parse_args(args(&["run", "8", "--set", "connections"])).is_err());
+        assert!(parse_args(args(&["run", "--all", "--set", "top=2"])).is_err());
+        assert!(parse_args(args(&["run", "8", "--set", "top=2", "--record"])).is_err());
+        assert!(parse_args(args(&["bench", "8", "--set", "top=2"])).is_err());
+        assert!(parse_args(args(&["bench", "--all"])).is_err());
+        assert!(parse_args(args(&["bench", "1", "--iterations", "0"])).is_err());
+        assert!(parse_args(args(&["bench", "1", "2"])).is_err());
+        assert!(parse_args(args(&["generate", "1", "2"])).is_err());
+        assert!(parse_args(args(&["fetch", "1", "--input", "-"])).is_err());
+        assert!(parse_args(args(&["submit", "1"])).is_err());
+        assert!(parse_args(args(&["submit", "1", "3"])).is_err());
+        assert!(parse_args(args(&["submit", "1", "2", "2"])).is_err());
+        assert!(parse_args(args(&["generate", "1", "--size", "0"])).is_err());
+        assert!(parse_args(args(&["diff", "1", "--cases", "none"])).is_err());
+        assert!(parse_args(args(&["generate", "1", "--difficulty", "extreme"])).is_err());
+    }
+}
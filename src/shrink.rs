@@ -0,0 +1,313 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{differential, registry::Puzzle};
+
+/// How an input fails, so shrinking keeps chasing the same bug instead of
+/// drifting onto a different one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Failure {
+    pub part: u8,
+    pub solver_panicked: bool,
+    pub naive_panicked: bool,
+}
+
+/// Ways of cutting an input down. Each produces candidates no larger than
+/// the input; days only use the ones that can keep their inputs well-formed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    /// Drop runs of whole lines, halving the run length down to one.
+    Lines,
+    /// Drop one `separator`-delimited item from a line.
+    Items(char),
+    /// Drop two neighbouring corners, which can keep an outline rectilinear.
+    Corners,
+    /// Drop the same column from every row.
+    Columns,
+    /// Drop one character from a line.
+    Chars,
+    /// Replace a number with a smaller one.
+    Numbers,
+    /// Blank out one digit, keeping column layouts intact.
+    Digits,
+}
+
+fn steps(day: u8) -> &'static [Step] {
+    match day {
+        2 => &[Step::Lines, Step::Items(','), Step::Numbers],
+        3 => &[Step::Lines, Step::Chars],
+        4 | 7 => &[Step::Lines, Step::Columns],
+        6 => &[Step::Lines, Step::Columns, Step::Digits],
+        9 => &[Step::Lines, Step::Corners, Step::Numbers],
+        10 => &[
+            Step::Lines,
+            Step::Items(' '),
+            Step::Items(','),
+            Step::Numbers,
+        ],
+        _ => &[Step::Lines, Step::Numbers],
+    }
+}
+
+/// Finds how `part` fails on `input` with `options` set: the solver disagrees
+/// with the naive reference, or either panics. Inputs that are not
+/// well-formed for the day never fail, so shrinking cannot wander into
+/// garbage.
+pub fn failure(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    options: &[(String, String)],
+    part: u8,
+) -> Option<Failure> {
+    if !well_formed(puzzle, input) {
+        return None;
+    }
+    if let Some((solver, naive)) = differential::compare(puzzle, input, options, part) {
+        return Some(Failure {
+            part,
+            solver_panicked: solver.is_none(),
+            naive_panicked: naive.is_none(),
+        });
+    }
+
+    // Without a naive answer to compare against, only a panic is a failure.
+    let mut parsed = puzzle.parse(input).ok()?;
+    for (name, value) in options {
+        puzzle.configure(parsed.as_mut(), name, value).ok()?;
+    }
+    panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve(parsed.as_ref(), part)))
+        .is_err()
+        .then_some(Failure {
+            part,
+            solver_panicked: true,
+            naive_panicked: false,
+        })
+}
+
+fn well_formed(puzzle: &dyn Puzzle, input: &str) -> bool {
+    let parsed = panic::catch_unwind(AssertUnwindSafe(|| puzzle.parse(input)));
+    matches!(parsed, Ok(Ok(_))) && !input.trim().is_empty()
+}
+
+/// Shrinks an input on which `part` fails with `options` set into a smaller
+/// one that fails the same way. Returns `None` if the input does not fail at
+/// all.
+pub fn shrink_failure(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    options: &[(String, String)],
+    part: u8,
+) -> Option<(String, Failure)> {
+    let original = failure(puzzle, input, options, part)?;
+    let shrunk = shrink(puzzle.day(), input, |candidate| {
+        failure(puzzle, candidate, options, part) == Some(original)
+    });
+    Some((shrunk, original))
+}
+
+/// Greedily applies the day's steps, keeping any candidate that is smaller
+/// and still satisfies `fails`, until no step makes progress.
+pub fn shrink(day: u8, input: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let mut current = input.to_string();
+    'progress: loop {
+        for &step in steps(day) {
+            for candidate in candidates(step, &current) {
+                if smaller(&candidate, &current) && fails(&candidate) {
+                    current = candidate;
+                    continue 'progress;
+                }
+            }
+        }
+        return current;
+    }
+}
+
+/// Shorter inputs are smaller; equal lengths compare by text, so shrinking a
+/// number digit by digit still makes progress and always stops.
+fn smaller(candidate: &str, current: &str) -> bool {
+    (candidate.len(), candidate) < (current.len(), current)
+}
+
+fn candidates(step: Step, text: &str) -> Vec<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let newline = if text.ends_with('\n') { "\n" } else { "" };
+    let join = |lines: Vec<&str>| lines.join("\n") + newline;
+    let mut candidates = Vec::new();
+
+    match step {
+        Step::Lines => {
+            let mut run = lines.len() / 2;
+            while run > 0 {
+                for start in (0..lines.len()).step_by(run) {
+                    let mut kept = lines.clone();
+                    kept.drain(start..(start + run).min(lines.len()));
+                    candidates.push(join(kept));
+                }
+                run /= 2;
+            }
+        }
+        Step::Items(separator) => {
+            for (index, line) in lines.iter().enumerate() {
+                let items: Vec<&str> = line.split(separator).collect();
+                for skip in 0..items.len() {
+                    let mut kept = items.clone();
+                    kept.remove(skip);
+                    let line = kept.join(&separator.to_string());
+                    let mut edited = lines.clone();
+                    edited[index] = &line;
+                    candidates.push(join(edited));
+                }
+            }
+        }
+        Step::Corners => {
+            for first in 0..lines.len() {
+                let second = (first + 1) % lines.len();
+                let kept = lines
+                    .iter()
+                    .enumerate()
+                    .filter(|&(index, _)| index != first && index != second)
+                    .map(|(_, line)| *line)
+                    .collect();
+                candidates.push(join(kept));
+            }
+        }
+        Step::Columns => {
+            let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+            for column in 0..width {
+                let cropped: Vec<String> =
+                    lines.iter().map(|line| remove_char(line, column)).collect();
+                candidates.push(join(cropped.iter().map(String::as_str).collect()));
+            }
+        }
+        Step::Chars => {
+            for (index, line) in lines.iter().enumerate() {
+                for column in 0..line.len() {
+                    let line = remove_char(line, column);
+                    let mut edited = lines.clone();
+                    edited[index] = &line;
+                    candidates.push(join(edited));
+                }
+            }
+        }
+        Step::Numbers => {
+            for (start, end) in digit_runs(text) {
+                let Ok(value) = text[start..end].parse::<u64>() else {
+                    continue;
+                };
+                for smaller in [0, 1, value / 2, value.saturating_sub(1)] {
+                    if smaller < value {
+                        candidates.push(format!("{}{smaller}{}", &text[..start], &text[end..]));
+                    }
+                }
+            }
+        }
+        Step::Digits => {
+            for (index, char) in text.char_indices() {
+                if char.is_ascii_digit() {
+                    candidates.push(format!("{} {}", &text[..index], &text[index + 1..]));
+                }
+            }
+        }
+    }
+    candidates
+}
+
+fn remove_char(line: &str, column: usize) -> String {
+    line.chars()
+        .enumerate()
+        .filter(|&(index, _)| index != column)
+        .map(|(_, char)| char)
+        .collect()
+}
+
+/// Byte ranges of every run of ASCII digits.
+fn digit_runs(text: &str) -> Vec<(usize, usize)> {
+    let mut runs = Vec::new();
+    let mut start = None;
+    for (index, byte) in text.bytes().chain([b' ']).enumerate() {
+        match (byte.is_ascii_digit(), start) {
+            (true, None) => start = Some(index),
+            (false, Some(run_start)) => {
+                runs.push((run_start, index));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Solution, day_9, error::ParseError, point::P2, registry};
+
+    #[test]
+    fn test_shrink_lines_and_numbers() {
+        let input = "R5\nL68\nR3\nL120\n";
+        let fails = |text: &str| {
+            text.lines()
+                .any(|line| line.starts_with('L') && line[1..].parse::<u32>().unwrap() >= 50)
+        };
+
+        assert_eq!(shrink(1, input, fails), "L50\n");
+    }
+
+    #[test]
+    fn test_shrink_columns() {
+        let input = "..@@.\n.@@@.\n@...@\n";
+        let fails = |text: &str| text.lines().any(|line| line.contains("@@@"));
+
+        assert_eq!(shrink(4, input, fails), "@@@\n");
+    }
+
+    #[test]
+    fn test_candidates_keep_column_layout() {
+        let got = candidates(Step::Digits, "12 3\n*  +");
+        assert_eq!(got, [" 2 3\n*  +", "1  3\n*  +", "12  \n*  +"]);
+    }
+
+    /// Day 9 with a second part that forgets the outline, fitting the
+    /// rectangle to the corners alone.
+    struct Unbounded;
+
+    impl Solution for Unbounded {
+        const DAY: u8 = 9;
+
+        type Input = Vec<P2<usize>>;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            <day_9::Day9 as Solution>::parse(input)
+        }
+
+        fn part_one(input: &Self::Input) -> Self::Answer1 {
+            day_9::largest_rect(input)
+        }
+
+        fn part_two(input: &Self::Input) -> Option<Self::Answer2> {
+            Some(day_9::largest_rect(input))
+        }
+
+        fn naive_part_two(input: &str, options: &[(String, String)]) -> Option<Self::Answer2> {
+            day_9::Day9::naive_part_two(input, options)
+        }
+    }
+
+    #[test]
+    fn test_shrink_failure_day_9() {
+        // An L with a notch in its top edge that shrinking can drop.
+        let input = "1,1\n3,1\n3,2\n4,2\n4,1\n10,1\n10,5\n5,5\n5,10\n1,10\n";
+        let (shrunk, failure) = shrink_failure(&Unbounded, input, &[], 2).unwrap();
+
+        assert!(!failure.solver_panicked && !failure.naive_panicked);
+        assert_eq!(shrunk, "1,1\n10,1\n10,5\n5,5\n5,10\n1,10\n");
+        assert_eq!(super::failure(&Unbounded, &shrunk, &[], 2), Some(failure));
+    }
+
+    #[test]
+    fn test_no_failure() {
+        let puzzle = registry::find(1).unwrap();
+        assert_eq!(shrink_failure(puzzle, "L68\nL30\n", &[], 2), None);
+    }
+}
@@ -0,0 +1,297 @@
+use std::{array, cmp::Ordering, collections::HashMap, iter};
+
+use crate::point::{Coordinate, Metric, Point};
+
+/// Every pair of points as `(distance, first, second)`, by the given
+/// metric, with `first < second`, closest first and then by index.
+///
+/// Pairs are found a shell at a time: points are bucketed into cubes as wide
+/// as the shell's outer radius, so each point only meets the points in its
+/// own and neighbouring buckets. Points within the radius by any of the
+/// metrics are within it along every axis too. Shells grow until the pairs run out, and
+/// only the shells that are asked for are ever built, so taking the first
+/// few pairs of a large cloud stays cheap.
+pub struct ClosestPairs<'a, const N: usize, T> {
+    points: &'a [Point<N, T>],
+    metric: Metric,
+    low: [f64; N],
+    extent: [f64; N],
+    /// The outer radius of the last shell, whose pairs are all found.
+    radius: f64,
+    /// Whether the last shell reached every pair.
+    finished: bool,
+    /// Pairs of the last shell not yet taken, furthest first.
+    shell: Vec<(T, usize, usize)>,
+    /// How many pairs the last shell held.
+    found: usize,
+    /// The distance below which pairs are skipped.
+    min: Option<T>,
+}
+
+impl<'a, const N: usize, T: Coordinate> ClosestPairs<'a, N, T> {
+    pub fn new(points: &'a [Point<N, T>], metric: Metric) -> Self {
+        let coords = |axis: usize| points.iter().map(move |point| point.0[axis].to_f64());
+        let low = array::from_fn(|axis| coords(axis).fold(f64::INFINITY, f64::min));
+        let extent = array::from_fn(|axis| {
+            let high = coords(axis).fold(f64::NEG_INFINITY, f64::max);
+            (high - low[axis]).max(0.0)
+        });
+        Self {
+            points,
+            metric,
+            low,
+            extent,
+            radius: 0.0,
+            finished: points.len() < 2,
+            shell: Vec::new(),
+            found: 0,
+            min: None,
+        }
+    }
+
+    /// Skips every pair closer than `dist`, without finding them first.
+    pub fn from_distance(mut self, dist: T) -> Self {
+        // Start the shells a hair inside `dist`, so that rounding cannot
+        // leave out pairs right at it. Any closer ones are skipped exactly.
+        self.radius = self.metric.length(dist.to_f64()) * (1.0 - 1e-9);
+        self.min = Some(dist);
+        self
+    }
+
+    /// The radius of the first shell: the spacing the points would have if
+    /// they were spread evenly over the axes they span, which takes in a few
+    /// pairs per point.
+    fn first_radius(&self) -> f64 {
+        let spanned: Vec<f64> = self.extent.iter().copied().filter(|&e| e > 0.0).collect();
+        if spanned.is_empty() {
+            return 1.0;
+        }
+        let volume: f64 = spanned.iter().product();
+        (volume / self.points.len() as f64).powf(1.0 / spanned.len() as f64)
+    }
+
+    /// Finds every pair further apart than the last shell's radius but
+    /// within `radius`.
+    fn fill_shell(&mut self, radius: f64) {
+        let inner = if self.radius == 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            self.metric.scale(self.radius)
+        };
+        let outer = self.metric.scale(radius);
+        // Buckets are a hair wider than the radius, so that rounding cannot
+        // put two points within it more than one bucket apart.
+        let width = radius * (1.0 + 1e-9);
+        let bucket = |point: &Point<N, T>| -> [i64; N] {
+            array::from_fn(|axis| ((point.0[axis].to_f64() - self.low[axis]) / width) as i64)
+        };
+
+        let mut buckets: HashMap<[i64; N], Vec<usize>> = HashMap::new();
+        for (index, point) in self.points.iter().enumerate() {
+            buckets.entry(bucket(point)).or_default().push(index);
+        }
+
+        // Each point meets the later points of its own bucket, and every point
+        // of the neighbouring buckets on one side, so each pair meets once.
+        let own = 3_usize.pow(N as u32) / 2;
+        let mut shell = Vec::new();
+        for (first, point) in self.points.iter().enumerate() {
+            let cell = bucket(point);
+            for offset in own..3_usize.pow(N as u32) {
+                let mut neighbour = cell;
+                let mut rest = offset;
+                for coord in &mut neighbour {
+                    *coord += (rest % 3) as i64 - 1;
+                    rest /= 3;
+                }
+                let Some(others) = buckets.get(&neighbour) else {
+                    continue;
+                };
+                for &second in others {
+                    if offset == own && second <= first {
+                        continue;
+                    }
+                    let distance = self.metric.distance(point, &self.points[second]);
+                    let estimate = distance.to_f64();
+                    if inner < estimate && estimate <= outer {
+                        shell.push((distance, first.min(second), first.max(second)));
+                    }
+                }
+            }
+        }
+        shell.sort_unstable_by(|a, b| b.0.total_cmp(&a.0).then((b.1, b.2).cmp(&(a.1, a.2))));
+
+        self.finished = self.metric.estimate(self.extent) <= outer;
+        self.radius = radius;
+        self.found = shell.len();
+        self.shell = shell;
+    }
+}
+
+impl<const N: usize, T: Coordinate> Iterator for ClosestPairs<'_, N, T> {
+    type Item = (T, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.shell.is_empty() && !self.finished {
+            // Grow the shells so that each holds about twice as many pairs as
+            // the last, or faster while they hold fewer than one per point.
+            let radius = if self.radius == 0.0 {
+                self.first_radius()
+            } else if self.found < self.points.len() {
+                self.radius * 2.0
+            } else {
+                self.radius * 2_f64.powf(1.0 / N as f64)
+            };
+            self.fill_shell(radius);
+            if let Some(min) = self.min {
+                self.shell.retain(|pair| pair.0.total_cmp(&min).is_ge());
+            }
+        }
+        self.shell.pop()
+    }
+}
+
+/// Orders two pairs of points, given by index, by their distance, then by
+/// the lesser point of each, then by the greater, and pairs of points at the
+/// same coordinates by index. Equally distant pairs then always come in the
+/// same order, whichever order the points are listed in.
+pub fn pair_order<const N: usize, T: Coordinate>(
+    points: &[Point<N, T>],
+    metric: Metric,
+    a: (usize, usize),
+    b: (usize, usize),
+) -> Ordering {
+    let key = |(first, second): (usize, usize)| {
+        let (first, second) = (&points[first], &points[second]);
+        let (low, high) = if first.total_cmp(second).is_gt() {
+            (second, first)
+        } else {
+            (first, second)
+        };
+        (metric.distance(first, second), low, high)
+    };
+    let ((dist_a, low_a, high_a), (dist_b, low_b, high_b)) = (key(a), key(b));
+    dist_a
+        .total_cmp(&dist_b)
+        .then_with(|| low_a.total_cmp(low_b))
+        .then_with(|| high_a.total_cmp(high_b))
+        .then(a.cmp(&b))
+}
+
+/// Every pair of points by index, in [`pair_order`]. Pairs are found through
+/// [`ClosestPairs`] as they are taken, so the first few stay cheap for large
+/// inputs.
+pub fn pairs_by_distance<const N: usize, T: Coordinate>(
+    points: &[Point<N, T>],
+    metric: Metric,
+) -> impl Iterator<Item = (usize, usize)> {
+    pairs_after(points, metric, None)
+}
+
+/// Like [`pairs_by_distance`], but only the pairs that come after `last`,
+/// skipping closer pairs without finding them.
+pub fn pairs_after<const N: usize, T: Coordinate>(
+    points: &[Point<N, T>],
+    metric: Metric,
+    last: Option<(usize, usize)>,
+) -> impl Iterator<Item = (usize, usize)> {
+    let mut pairs = ClosestPairs::new(points, metric);
+    if let Some((first, second)) = last {
+        pairs = pairs.from_distance(metric.distance(&points[first], &points[second]));
+    }
+    let mut pairs = pairs
+        .filter(move |&(_, first, second)| {
+            last.is_none_or(|last| pair_order(points, metric, (first, second), last).is_gt())
+        })
+        .peekable();
+    // Equally distant pairs not yet taken, last in order first.
+    let mut ties = Vec::new();
+    iter::from_fn(move || {
+        if ties.is_empty() {
+            let (dist, first, second) = pairs.next()?;
+            ties.push((first, second));
+            while let Some((_, first, second)) =
+                pairs.next_if(|(next, ..)| next.total_cmp(&dist).is_eq())
+            {
+                ties.push((first, second));
+            }
+            ties.sort_by(|&a, &b| pair_order(points, metric, b, a));
+        }
+        ties.pop()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate::Rng, point::P3};
+
+    fn cloud(rng: &mut Rng, len: usize, max: u64) -> Vec<P3<u64>> {
+        (0..len)
+            .map(|_| Point([0; 3].map(|_: u64| rng.range(0..=max))))
+            .collect()
+    }
+
+    fn all_pairs<T: Coordinate>(points: &[P3<T>], metric: Metric) -> Vec<(T, usize, usize)> {
+        let mut pairs = Vec::new();
+        for first in 0..points.len() {
+            for second in first + 1..points.len() {
+                let distance = metric.distance(&points[first], &points[second]);
+                pairs.push((distance, first, second));
+            }
+        }
+        pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+        pairs
+    }
+
+    #[test]
+    fn test_closest_pairs_match_all_pairs() {
+        let mut rng = Rng::new(7);
+        for metric in [Metric::Manhattan, Metric::Chebyshev, Metric::Euclidean] {
+            for (len, max) in [(0, 9), (1, 9), (2, 0), (2, 9), (40, 3), (60, 1000), (80, 0)] {
+                let points = cloud(&mut rng, len, max);
+                let got: Vec<_> = ClosestPairs::new(&points, metric).collect();
+                let want = all_pairs(&points, metric);
+                assert_eq!(got, want, "{len} points up to {max} by {metric}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_closest_pairs_floats() {
+        let mut rng = Rng::new(5);
+        let points: Vec<P3<f64>> = cloud(&mut rng, 60, 2000)
+            .into_iter()
+            .map(|point| Point(point.0.map(|coord| coord as f64 / 1000.0 - 1.0)))
+            .collect();
+        for metric in [Metric::Manhattan, Metric::Chebyshev, Metric::Euclidean] {
+            let got: Vec<_> = ClosestPairs::new(&points, metric).collect();
+            assert_eq!(got, all_pairs(&points, metric), "by {metric}");
+        }
+    }
+
+    #[test]
+    fn test_pairs_after() {
+        let mut rng = Rng::new(9);
+        for metric in [Metric::Manhattan, Metric::Chebyshev, Metric::Euclidean] {
+            // Few distinct coordinates, so that many pairs tie.
+            let points = cloud(&mut rng, 30, 4);
+            let all: Vec<_> = pairs_by_distance(&points, metric).collect();
+            assert_eq!(all.len(), 30 * 29 / 2);
+            for (index, &last) in all.iter().enumerate() {
+                let rest: Vec<_> = pairs_after(&points, metric, Some(last)).collect();
+                assert_eq!(rest, all[index + 1..], "after {last:?} by {metric}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_closest_pairs_large_cloud() {
+        let points = cloud(&mut Rng::new(1), 100_000, 1_000_000);
+        let got: Vec<_> = ClosestPairs::new(&points, Metric::Euclidean)
+            .take(1000)
+            .collect();
+        assert_eq!(got.len(), 1000);
+        assert!(got.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,398 @@
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+use crate::{
+    answers::{quote, unquote},
+    client::Reply,
+};
+
+pub const DEFAULT_SUBMISSIONS: &str = "submissions.toml";
+
+/// The site's verdict on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Feedback {
+    /// Replies that say nothing about the answer itself, such as being told
+    /// to wait, have no feedback.
+    pub fn from_reply(reply: &Reply) -> Option<Self> {
+        match reply {
+            Reply::Correct => Some(Feedback::Correct),
+            Reply::TooHigh => Some(Feedback::TooHigh),
+            Reply::TooLow => Some(Feedback::TooLow),
+            Reply::Wrong => Some(Feedback::Wrong),
+            Reply::Wait(_) | Reply::WrongLevel | Reply::Unrecognized(_) => None,
+        }
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        match text {
+            "correct" => Some(Feedback::Correct),
+            "too high" => Some(Feedback::TooHigh),
+            "too low" => Some(Feedback::TooLow),
+            "wrong" => Some(Feedback::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Feedback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Feedback::Correct => write!(f, "correct"),
+            Feedback::TooHigh => write!(f, "too high"),
+            Feedback::TooLow => write!(f, "too low"),
+            Feedback::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// Why an answer should not be sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    Solved {
+        answer: String,
+    },
+    Rejected(Feedback),
+    /// At or below an answer that was too low.
+    BelowBound {
+        bound: i128,
+    },
+    /// At or above an answer that was too high.
+    AboveBound {
+        bound: i128,
+    },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Solved { answer } => write!(f, "already solved with {answer}"),
+            Refusal::Rejected(feedback) => write!(f, "already submitted and was {feedback}"),
+            Refusal::BelowBound { bound } => write!(f, "{bound} was already too low"),
+            Refusal::AboveBound { bound } => write!(f, "{bound} was already too high"),
+        }
+    }
+}
+
+/// Whose answers an entry holds. Each year and account gets its own puzzles,
+/// so feedback on one says nothing about another. Sessions are kept as a
+/// fingerprint so the cookie itself never reaches the submissions file.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Account {
+    pub year: u16,
+    pub session: String,
+}
+
+impl Account {
+    pub fn new(year: u16, session: &str) -> Self {
+        // 64-bit FNV-1a, which unlike std's hashers is fixed across releases.
+        let hash = session
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            });
+        Self {
+            year,
+            session: format!("{hash:016x}"),
+        }
+    }
+}
+
+/// Every answer submitted per year, session, day and part, in order, with the
+/// feedback it got. Stored as a small TOML file with one table per part:
+///
+/// ```toml
+/// [2025.af63bd4c8601b7df.day1.part2]
+/// "6801" = "too high"
+/// "6789" = "correct"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Submissions {
+    entries: BTreeMap<(Account, u8, u8), Vec<(String, Feedback)>>,
+}
+
+impl Submissions {
+    pub fn attempts(&self, account: &Account, day: u8, part: u8) -> &[(String, Feedback)] {
+        self.entries
+            .get(&(account.clone(), day, part))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Records `feedback` for `answer`, replacing any earlier feedback for it.
+    pub fn record(
+        &mut self,
+        account: &Account,
+        day: u8,
+        part: u8,
+        answer: &str,
+        feedback: Feedback,
+    ) {
+        let attempts = self
+            .entries
+            .entry((account.clone(), day, part))
+            .or_default();
+        attempts.retain(|(tried, _)| tried != answer);
+        attempts.push((answer.to_string(), feedback));
+    }
+
+    /// The exclusive range a numeric answer must fall in, from the highest
+    /// answer that was too low and the lowest that was too high.
+    pub fn bounds(&self, account: &Account, day: u8, part: u8) -> (Option<i128>, Option<i128>) {
+        let numbers = |want: Feedback| {
+            self.attempts(account, day, part)
+                .iter()
+                .filter(move |(_, feedback)| *feedback == want)
+                .filter_map(|(answer, _)| answer.parse::<i128>().ok())
+        };
+        (
+            numbers(Feedback::TooLow).max(),
+            numbers(Feedback::TooHigh).min(),
+        )
+    }
+
+    pub fn check(&self, account: &Account, day: u8, part: u8, answer: &str) -> Result<(), Refusal> {
+        let attempts = self.attempts(account, day, part);
+        if let Some((solved, _)) = attempts
+            .iter()
+            .find(|(_, feedback)| *feedback == Feedback::Correct)
+        {
+            return Err(Refusal::Solved {
+                answer: solved.clone(),
+            });
+        }
+        if let Some((_, feedback)) = attempts.iter().find(|(tried, _)| tried == answer) {
+            return Err(Refusal::Rejected(*feedback));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        match self.bounds(account, day, part) {
+            (Some(bound), _) if value <= bound => Err(Refusal::BelowBound { bound }),
+            (_, Some(bound)) if value >= bound => Err(Refusal::AboveBound { bound }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Loads submissions from `path`, treating a missing file as empty.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| {
+                let message = format!("{}:{err}", path.display());
+                io::Error::new(io::ErrorKind::InvalidData, message)
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_toml())
+    }
+
+    /// Parses the TOML subset written by [`Submissions::to_toml`]. Errors
+    /// carry the 1-based line number, as `"<line>: <message>"`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut submissions = Self::default();
+        let mut table = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or(format!("{line_number}: expected ']' to close the table"))?;
+                table = Some(
+                    parse_header(header)
+                        .ok_or(format!("{line_number}: expected [YEAR.SESSION.dayN.partP]"))?,
+                );
+                continue;
+            }
+
+            let (account, day, part) = table.clone().ok_or(format!(
+                "{line_number}: answer outside of a [YEAR.SESSION.dayN.partP] table"
+            ))?;
+            let (answer, feedback) = parse_attempt(line).ok_or(format!(
+                r#"{line_number}: expected "<answer>" = "<feedback>""#
+            ))?;
+            let feedback = Feedback::parse(&feedback)
+                .ok_or(format!("{line_number}: unknown feedback {feedback:?}"))?;
+            submissions.record(&account, day, part, &answer, feedback);
+        }
+
+        Ok(submissions)
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut out = String::from(
+            "# Submitted answers per year, session, day and part, with the feedback they got.\n",
+        );
+        for ((account, day, part), attempts) in &self.entries {
+            out += &format!(
+                "\n[{}.{}.day{day}.part{part}]\n",
+                account.year, account.session
+            );
+            for (answer, feedback) in attempts {
+                out += &format!("{} = {}\n", quote(answer), quote(&feedback.to_string()));
+            }
+        }
+        out
+    }
+}
+
+fn parse_header(header: &str) -> Option<(Account, u8, u8)> {
+    let [year, session, day, part] = header.trim().split('.').collect::<Vec<_>>()[..] else {
+        return None;
+    };
+    let year = year.parse().ok()?;
+    if session.is_empty() || !session.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    let session = session.to_string();
+    let day = day.strip_prefix("day")?.parse().ok()?;
+    let part = part.strip_prefix("part")?.parse().ok()?;
+    matches!(part, 1 | 2).then_some((Account { year, session }, day, part))
+}
+
+/// Splits `"<answer>" = "<feedback>"` at the first unescaped closing quote.
+fn parse_attempt(line: &str) -> Option<(String, String)> {
+    let mut escaped = false;
+    let (end, _) = line.char_indices().skip(1).find(|&(_, char)| {
+        let closes = char == '"' && !escaped;
+        escaped = char == '\\' && !escaped;
+        closes
+    })?;
+    let (key, value) = line.split_at(end + 1);
+    let value = value.trim_start().strip_prefix('=')?;
+    Some((unquote(key)?, unquote(value.trim())?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account() -> Account {
+        Account::new(2025, "cafe")
+    }
+
+    #[test]
+    fn test_check_known_answers() {
+        let mut submissions = Submissions::default();
+        submissions.record(&account(), 1, 2, "6801", Feedback::Wrong);
+
+        assert_eq!(
+            submissions.check(&account(), 1, 2, "6801"),
+            Err(Refusal::Rejected(Feedback::Wrong))
+        );
+        assert_eq!(submissions.check(&account(), 1, 2, "6789"), Ok(()));
+        assert_eq!(submissions.check(&account(), 1, 1, "6801"), Ok(()));
+
+        submissions.record(&account(), 1, 2, "6789", Feedback::Correct);
+        assert_eq!(
+            submissions.check(&account(), 1, 2, "6790"),
+            Err(Refusal::Solved {
+                answer: "6789".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_per_account() {
+        let mut submissions = Submissions::default();
+        submissions.record(&account(), 1, 2, "6789", Feedback::Correct);
+        submissions.record(&account(), 1, 1, "100", Feedback::TooLow);
+
+        let last_year = Account::new(2024, "cafe");
+        let other_session = Account::new(2025, "f00d");
+        assert_eq!(submissions.check(&last_year, 1, 2, "6790"), Ok(()));
+        assert_eq!(submissions.check(&other_session, 1, 2, "6790"), Ok(()));
+        assert_eq!(submissions.check(&other_session, 1, 1, "50"), Ok(()));
+        assert_eq!(submissions.bounds(&other_session, 1, 1), (None, None));
+    }
+
+    #[test]
+    fn test_account_hides_session() {
+        let account = Account::new(2025, "53616c7465645f5f");
+        assert_eq!(account.session.len(), 16);
+        assert_ne!(account.session, "53616c7465645f5f");
+        assert_eq!(account, Account::new(2025, "53616c7465645f5f"));
+    }
+
+    #[test]
+    fn test_check_bounds() {
+        let mut submissions = Submissions::default();
+        submissions.record(&account(), 8, 1, "200000", Feedback::TooHigh);
+        submissions.record(&account(), 8, 1, "100000", Feedback::TooLow);
+        submissions.record(&account(), 8, 1, "150000", Feedback::TooHigh);
+        submissions.record(&account(), 8, 1, "90000", Feedback::TooLow);
+
+        assert_eq!(
+            submissions.bounds(&account(), 8, 1),
+            (Some(100000), Some(150000))
+        );
+        assert_eq!(submissions.check(&account(), 8, 1, "121770"), Ok(()));
+        assert_eq!(
+            submissions.check(&account(), 8, 1, "100000"),
+            Err(Refusal::Rejected(Feedback::TooLow))
+        );
+        assert_eq!(
+            submissions.check(&account(), 8, 1, "95000"),
+            Err(Refusal::BelowBound { bound: 100000 })
+        );
+        assert_eq!(
+            submissions.check(&account(), 8, 1, "180000"),
+            Err(Refusal::AboveBound { bound: 150000 })
+        );
+        assert_eq!(submissions.check(&account(), 8, 1, "not a number"), Ok(()));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut submissions = Submissions::default();
+        submissions.record(&account(), 1, 2, "6801", Feedback::TooHigh);
+        submissions.record(&account(), 1, 2, "6789", Feedback::Correct);
+        submissions.record(&account(), 10, 1, "odd \"answer\"", Feedback::Wrong);
+        submissions.record(&Account::new(2024, "cafe"), 1, 2, "6801", Feedback::Wrong);
+
+        let text = submissions.to_toml();
+        let table = format!("[2025.{}.day1.part2]\n", account().session);
+        assert!(text.contains(&format!(
+            "{table}\"6801\" = \"too high\"\n\"6789\" = \"correct\"\n"
+        )));
+        assert_eq!(Submissions::parse(&text), Ok(submissions));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Submissions::parse("\"1\" = \"wrong\""),
+            Err("1: answer outside of a [YEAR.SESSION.dayN.partP] table".to_string())
+        );
+        assert_eq!(
+            Submissions::parse("[2025.cafe.day1.part3]"),
+            Err("1: expected [YEAR.SESSION.dayN.partP]".to_string())
+        );
+        assert_eq!(
+            Submissions::parse("[day1.part1]"),
+            Err("1: expected [YEAR.SESSION.dayN.partP]".to_string())
+        );
+        assert_eq!(
+            Submissions::parse("[2025.cafe.day1.part1]\n\"1\" = \"close\""),
+            Err("2: unknown feedback \"close\"".to_string())
+        );
+        assert_eq!(
+            Submissions::parse("[2025.cafe.day1.part1]\n1 = \"wrong\""),
+            Err("2: expected \"<answer>\" = \"<feedback>\"".to_string())
+        );
+    }
+}
@@ -0,0 +1,126 @@
+/// Disjoint sets over `0..len`, with path compression and union by size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// Every element starts in a set of its own.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// Adds an element in a set of its own, and returns it.
+    pub fn push(&mut self) -> usize {
+        let element = self.len();
+        self.parent.push(element);
+        self.size.push(1);
+        self.components += 1;
+        element
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of the set holding `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut current = element;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    /// Merges the sets holding `a` and `b`. Returns `false` if they were
+    /// already the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (big, small) = if self.size[a] >= self.size[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[small] = big;
+        self.size[big] += self.size[small];
+        self.components -= 1;
+        true
+    }
+
+    /// How many elements share a set with `element`.
+    pub fn size_of(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// How many separate sets there are.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// The size of every set, largest first.
+    pub fn sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = (0..self.len())
+            .filter(|&element| self.parent[element] == element)
+            .map(|root| self.size[root])
+            .collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert_eq!(sets.find(0), sets.find(3));
+        assert_ne!(sets.find(0), sets.find(4));
+        assert_eq!(sets.size_of(2), 4);
+        assert_eq!(sets.components(), 3);
+        assert_eq!(sets.sizes(), [4, 1, 1]);
+    }
+
+    #[test]
+    fn test_path_compression() {
+        let mut sets = UnionFind::new(5);
+        for element in 1..5 {
+            sets.union(element - 1, element);
+        }
+        let root = sets.find(4);
+        assert!((0..5).all(|element| sets.parent[element] == root));
+    }
+
+    #[test]
+    fn test_push() {
+        let mut sets = UnionFind::new(2);
+        sets.union(0, 1);
+        assert_eq!(sets.push(), 2);
+        assert_eq!(sets.components(), 2);
+        assert!(sets.union(1, 2));
+        assert_eq!(sets.sizes(), [3]);
+    }
+}
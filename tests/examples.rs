@@ -0,0 +1,21 @@
+use std::path::Path;
+
+use advent_of_code::fixtures::{self, EXAMPLES_DIR};
+
+#[test]
+fn test_examples() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(EXAMPLES_DIR);
+    let fixtures = fixtures::discover(&dir).expect("examples directory should be readable");
+    assert!(
+        !fixtures.is_empty(),
+        "no fixtures found in {}",
+        dir.display()
+    );
+
+    let failures: Vec<String> = fixtures.iter().flat_map(fixtures::check).collect();
+    assert!(
+        failures.is_empty(),
+        "failing examples:\n{}",
+        failures.join("\n")
+    );
+}